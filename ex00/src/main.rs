@@ -1,11 +1,7 @@
-mod field;
-mod matrix;
-mod vector;
-
-use matrix::Matrix;
-use vector::Vector;
+use matrix::{Matrix, Vector};
 
 #[cfg(test)]
+#[allow(clippy::items_after_test_module)]
 mod vectors {
     use super::*;
     use approx::assert_abs_diff_eq;
@@ -1,9 +1,7 @@
-mod field;
-mod vector;
-
-use vector::Vector;
+use matrix::Vector;
 
 #[cfg(test)]
+#[allow(clippy::items_after_test_module)]
 mod vectors {
     use super::*;
     use approx::assert_abs_diff_eq;
@@ -1,20 +1,7 @@
-mod field;
-mod matrix;
-mod vector;
-
-use matrix::Matrix;
-use vector::Vector;
-
-use std::ops::{Add, Mul, Sub};
-
-fn lerp<T, K>(u: T, v: T, t: K) -> T
-where
-    T: Add<Output = T> + Sub<Output = T> + Mul<K, Output = T> + Clone,
-{
-    u.clone() + (v - u) * t
-}
+use matrix::{lerp, Matrix, Vector};
 
 #[cfg(test)]
+#[allow(clippy::items_after_test_module)]
 mod vectors {
     use super::*;
     use approx::assert_abs_diff_eq;
@@ -38,8 +25,8 @@ mod vectors {
                 .try_into()
                 .unwrap();
 
-            let my_v1 = Vector::new(v1.clone());
-            let my_v2 = Vector::new(v2.clone());
+            let my_v1 = Vector::new(v1);
+            let my_v2 = Vector::new(v2);
             let nalgebra_v1 = SVector::<f64, N>::from_vec(v1.to_vec());
             let nalgebra_v2 = SVector::<f64, N>::from_vec(v2.to_vec());
 
@@ -48,7 +35,7 @@ mod vectors {
             let my_result = lerp(my_v1, my_v2, t);
             let nalgebra_result = nalgebra_v1.lerp(&nalgebra_v2, t);
 
-            for (i, &value) in my_result.data().iter().enumerate() {
+            for (i, &value) in my_result.iter().enumerate() {
                 assert_abs_diff_eq!(value, nalgebra_result[i], epsilon = THRESHOLD);
             }
         }
@@ -111,8 +98,8 @@ mod matrices {
                 .try_into()
                 .unwrap();
 
-            let my_m1 = Matrix::new(m1.clone());
-            let my_m2 = Matrix::new(m2.clone());
+            let my_m1 = Matrix::new(m1);
+            let my_m2 = Matrix::new(m2);
             let nalgebra_m1 =
                 SMatrix::<f64, M, N>::from_vec(m1.iter().flatten().copied().collect());
             let nalgebra_m2 =
@@ -123,7 +110,7 @@ mod matrices {
             let my_result = lerp(my_m1, my_m2, t);
             let nalgebra_result = nalgebra_m1 * (1.0 - t) + nalgebra_m2 * t;
 
-            for (i, &value) in my_result.data().iter().flatten().enumerate() {
+            for (i, &value) in my_result.iter().flatten().enumerate() {
                 assert_abs_diff_eq!(value, nalgebra_result[i], epsilon = THRESHOLD);
             }
         }
@@ -1,9 +1,7 @@
-mod field;
-mod vector;
-
-use vector::Vector;
+use matrix::Vector;
 
 #[cfg(test)]
+#[allow(clippy::items_after_test_module)]
 mod dot_product_tests {
     use super::*;
     use approx::assert_abs_diff_eq;
@@ -27,8 +25,8 @@ mod dot_product_tests {
                 .try_into()
                 .unwrap();
 
-            let my_v1 = Vector::new(v1.clone());
-            let my_v2 = Vector::new(v2.clone());
+            let my_v1 = Vector::new(v1);
+            let my_v2 = Vector::new(v2);
             let nalgebra_v1 = SVector::<f64, N>::from_vec(v1.to_vec());
             let nalgebra_v2 = SVector::<f64, N>::from_vec(v2.to_vec());
 
@@ -1,9 +1,7 @@
-mod field;
-mod vector;
-
-use vector::Vector;
+use matrix::Vector;
 
 #[cfg(test)]
+#[allow(clippy::items_after_test_module)]
 mod vector_tests {
     use super::*;
     use approx::assert_abs_diff_eq;
@@ -22,7 +20,7 @@ mod vector_tests {
                 .try_into()
                 .unwrap();
 
-            let my_v = Vector::new(v.clone());
+            let my_v = Vector::new(v);
             let nalgebra_v = SVector::<f64, N>::from_vec(v.to_vec());
 
             assert_abs_diff_eq!(
@@ -1,21 +1,7 @@
-mod field;
-mod vector;
-
-use field::*;
-use vector::Vector;
-
-fn angle_cos<K: Field, const N: usize>(u: &Vector<K, N>, v: &Vector<K, N>) -> f32 {
-    let norm_u = u.norm();
-    let norm_v = v.norm();
-
-    if norm_u == K::zero().into() || norm_v == K::zero().into() {
-        panic!("One or both vectors are zero");
-    }
-
-    (u.dot(v).into() / (u.norm() * v.norm())) as f32
-}
+use matrix::{angle_cos, Vector};
 
 #[cfg(test)]
+#[allow(clippy::items_after_test_module)]
 mod tests {
     use super::*;
     use approx::assert_abs_diff_eq;
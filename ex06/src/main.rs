@@ -1,18 +1,7 @@
-mod field;
-mod vector;
-
-use field::*;
-use vector::Vector;
-
-fn cross_product<K: Field>(u: &Vector<K, 3>, v: &Vector<K, 3>) -> Vector<K, 3> {
-    Vector::new([
-        u.data()[1] * v.data()[2] - u.data()[2] * v.data()[1],
-        u.data()[2] * v.data()[0] - u.data()[0] * v.data()[2],
-        u.data()[0] * v.data()[1] - u.data()[1] * v.data()[0],
-    ])
-}
+use matrix::{cross_product, Vector};
 
 #[cfg(test)]
+#[allow(clippy::items_after_test_module)]
 mod tests {
     use super::*;
     use approx::assert_abs_diff_eq;
@@ -45,7 +34,7 @@ mod tests {
             let my_cross_product = cross_product(&my_v1, &my_v2);
             let nalgebra_cross_product = nalgebra_v1.cross(&nalgebra_v2);
 
-            for (i, &value) in my_cross_product.data().iter().enumerate() {
+            for (i, &value) in my_cross_product.iter().enumerate() {
                 assert_abs_diff_eq!(value, nalgebra_cross_product[i], epsilon = THRESHOLD);
             }
         }
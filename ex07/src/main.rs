@@ -1,9 +1,4 @@
-mod field;
-mod matrix;
-mod vector;
-
-use matrix::Matrix;
-use vector::Vector;
+use matrix::{Matrix, Vector};
 
 fn main() {
     let u = Matrix::from([[1., 0.], [0., 1.]]);
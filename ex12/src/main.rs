@@ -1,9 +1,4 @@
-mod field;
-mod matrix;
-mod vector;
-
-use field::*;
-use matrix::Matrix;
+use matrix::{Field, Matrix};
 use std::fmt::Display;
 
 fn print_inverse<K: Field + Display, const N: usize>(matrix: Matrix<K, N, N>) {
@@ -1,7 +1,3 @@
-mod field;
-mod matrix;
-mod vector;
-
 use matrix::Matrix;
 
 fn main() {
@@ -0,0 +1,496 @@
+use crate::dmatrix::DMatrix;
+use crate::dvector::DVector;
+use crate::field::{Field, Ring};
+use crate::matrix::Matrix;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tridiagonal<K> {
+    lower: Vec<K>,
+    diag: Vec<K>,
+    upper: Vec<K>,
+}
+
+impl<K: Ring> Tridiagonal<K> {
+    /// `lower` and `upper` hold the sub- and super-diagonal, one element
+    /// shorter than `diag`.
+    pub fn new(lower: Vec<K>, diag: Vec<K>, upper: Vec<K>) -> Self {
+        let off = diag.len().saturating_sub(1);
+        assert!(
+            lower.len() == off && upper.len() == off,
+            "Off-diagonals must be one shorter than the diagonal"
+        );
+        Tridiagonal { lower, diag, upper }
+    }
+
+    pub fn dim(&self) -> usize {
+        self.diag.len()
+    }
+
+    pub fn lower(&self) -> &[K] {
+        &self.lower
+    }
+
+    pub fn diag(&self) -> &[K] {
+        &self.diag
+    }
+
+    pub fn upper(&self) -> &[K] {
+        &self.upper
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> K {
+        assert!(row < self.dim() && col < self.dim(), "Index out of bounds");
+        if row == col {
+            self.diag[row]
+        } else if row == col + 1 {
+            self.lower[col]
+        } else if col == row + 1 {
+            self.upper[row]
+        } else {
+            K::zero()
+        }
+    }
+
+    pub fn mul_vec(&self, rhs: &[K]) -> DVector<K> {
+        let n = self.dim();
+        assert_eq!(n, rhs.len(), "Vector dimension must match columns");
+        DVector::new(
+            (0..n)
+                .map(|i| {
+                    let mut acc = self.diag[i] * rhs[i];
+                    if i > 0 {
+                        acc += self.lower[i - 1] * rhs[i - 1];
+                    }
+                    if i + 1 < n {
+                        acc += self.upper[i] * rhs[i + 1];
+                    }
+                    acc
+                })
+                .collect(),
+        )
+    }
+
+    pub fn to_dense(&self) -> DMatrix<K> {
+        let n = self.dim();
+        let mut result = DMatrix::zeros(n, n);
+        for i in 0..n {
+            result[i][i] = self.diag[i];
+            if i + 1 < n {
+                result[i + 1][i] = self.lower[i];
+                result[i][i + 1] = self.upper[i];
+            }
+        }
+        result
+    }
+
+    /// Continuant recurrence `f(k) = d(k)·f(k-1) - l(k-1)·u(k-1)·f(k-2)`,
+    /// division free so it also works over integers.
+    pub fn determinant(&self) -> K {
+        let (mut previous, mut current) = (K::one(), K::one());
+        for k in 0..self.dim() {
+            let mut next = self.diag[k] * current;
+            if k > 0 {
+                next -= self.lower[k - 1] * self.upper[k - 1] * previous;
+            }
+            previous = current;
+            current = next;
+        }
+        current
+    }
+}
+
+impl<K: Field> Tridiagonal<K> {
+    /// Thomas algorithm in O(n). A vanishing pivot does not imply
+    /// singularity, so that case falls back to the pivoted banded solver.
+    pub fn solve(&self, rhs: &[K]) -> Result<DVector<K>, &'static str> {
+        let n = self.dim();
+        assert_eq!(n, rhs.len(), "Vector dimension must match columns");
+
+        let mut upper = Vec::with_capacity(n);
+        let mut result = Vec::with_capacity(n);
+        for i in 0..n {
+            let mut pivot = self.diag[i];
+            let mut value = rhs[i];
+            if i > 0 {
+                pivot -= self.lower[i - 1] * upper[i - 1];
+                value -= self.lower[i - 1] * result[i - 1];
+            }
+            if pivot.may_be_zero() {
+                return Banded::from(self).solve(rhs);
+            }
+            if i + 1 < n {
+                upper.push(self.upper[i] / pivot);
+            }
+            result.push(value / pivot);
+        }
+
+        for i in (0..n.saturating_sub(1)).rev() {
+            let next = result[i + 1];
+            result[i] -= upper[i] * next;
+        }
+
+        Ok(DVector::new(result))
+    }
+}
+
+/// Square matrix with `kl` sub-diagonals and `ku` super-diagonals. Row `i`
+/// stores columns `i - kl ..= i + ku` contiguously.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Banded<K> {
+    n: usize,
+    kl: usize,
+    ku: usize,
+    data: Vec<K>,
+}
+
+impl<K: Ring> Banded<K> {
+    pub fn zeros(n: usize, kl: usize, ku: usize) -> Self {
+        Banded {
+            n,
+            kl,
+            ku,
+            data: vec![K::zero(); n * (kl + ku + 1)],
+        }
+    }
+
+    /// Keeps the entries of `matrix` inside the band; the rest must be zero.
+    pub fn from_dense(matrix: &DMatrix<K>, kl: usize, ku: usize) -> Result<Self, &'static str> {
+        if matrix.rows() != matrix.cols() {
+            return Err("Matrix is not square");
+        }
+
+        let mut result = Banded::zeros(matrix.rows(), kl, ku);
+        for i in 0..matrix.rows() {
+            for (j, &value) in matrix[i].iter().enumerate() {
+                if result.in_band(i, j) {
+                    result.set(i, j, value);
+                } else if value != K::zero() {
+                    return Err("Matrix has entries outside the band");
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    pub fn dim(&self) -> usize {
+        self.n
+    }
+
+    pub fn lower_bandwidth(&self) -> usize {
+        self.kl
+    }
+
+    pub fn upper_bandwidth(&self) -> usize {
+        self.ku
+    }
+
+    fn in_band(&self, row: usize, col: usize) -> bool {
+        col + self.kl >= row && col <= row + self.ku
+    }
+
+    fn index(&self, row: usize, col: usize) -> usize {
+        row * (self.kl + self.ku + 1) + col + self.kl - row
+    }
+
+    /// Columns of `row` that fall inside the band.
+    fn band_cols(&self, row: usize) -> std::ops::Range<usize> {
+        row.saturating_sub(self.kl)..(row + self.ku + 1).min(self.n)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> K {
+        assert!(row < self.n && col < self.n, "Index out of bounds");
+        if self.in_band(row, col) {
+            self.data[self.index(row, col)]
+        } else {
+            K::zero()
+        }
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: K) {
+        assert!(row < self.n && col < self.n, "Index out of bounds");
+        assert!(self.in_band(row, col), "Index outside the band");
+        let index = self.index(row, col);
+        self.data[index] = value;
+    }
+
+    pub fn mul_vec(&self, rhs: &[K]) -> DVector<K> {
+        assert_eq!(self.n, rhs.len(), "Vector dimension must match columns");
+        DVector::new(
+            (0..self.n)
+                .map(|i| {
+                    self.band_cols(i)
+                        .fold(K::zero(), |acc, j| acc + self.get(i, j) * rhs[j])
+                })
+                .collect(),
+        )
+    }
+
+    pub fn to_dense(&self) -> DMatrix<K> {
+        let mut result = DMatrix::zeros(self.n, self.n);
+        for i in 0..self.n {
+            for j in self.band_cols(i) {
+                result[i][j] = self.get(i, j);
+            }
+        }
+        result
+    }
+}
+
+impl<K: Field> Banded<K> {
+    /// LU with partial pivoting. Row swaps widen the upper band of U to
+    /// `kl + ku`, so the factors live in a `Banded` with that bandwidth.
+    /// Returns the factors, the right-hand side permuted alongside, and the
+    /// swap parity. O(n·kl·(kl + ku)).
+    ///
+    /// Like `LU`, pivots that may be zero do not stop the elimination, so the
+    /// diagonal of U still encloses the determinant; `solve` rejects them.
+    fn factorize(&self, mut rhs: Vec<K>) -> (Banded<K>, Vec<K>, K) {
+        let n = self.n;
+        let mut lu = Banded::zeros(n, self.kl, self.kl + self.ku);
+        for i in 0..n {
+            for j in self.band_cols(i) {
+                lu.set(i, j, self.get(i, j));
+            }
+        }
+
+        let mut sign = K::one();
+        for k in 0..n {
+            let last = (k + self.kl).min(n - 1);
+            let mut pivot = k;
+            for row in (k + 1)..=last {
+                if lu.get(row, k).is_better_pivot(lu.get(pivot, k)) {
+                    pivot = row;
+                }
+            }
+
+            let end = (k + lu.ku + 1).min(n);
+            if pivot != k {
+                for j in k..end {
+                    let (a, b) = (lu.index(k, j), lu.index(pivot, j));
+                    lu.data.swap(a, b);
+                }
+                rhs.swap(k, pivot);
+                sign = -sign;
+            }
+
+            // The best pivot is exactly zero only when the whole column is.
+            let diag = lu.get(k, k);
+            if diag == K::zero() {
+                continue;
+            }
+            for row in (k + 1)..=last {
+                if lu.get(row, k) == K::zero() {
+                    continue;
+                }
+                let factor = lu.get(row, k) / diag;
+                for j in (k + 1)..end {
+                    let value = lu.get(row, j) - factor * lu.get(k, j);
+                    lu.set(row, j, value);
+                }
+                lu.set(row, k, factor);
+                let value = rhs[k];
+                rhs[row] -= factor * value;
+            }
+        }
+
+        (lu, rhs, sign)
+    }
+
+    pub fn solve(&self, rhs: &[K]) -> Result<DVector<K>, &'static str> {
+        assert_eq!(self.n, rhs.len(), "Vector dimension must match columns");
+        let (lu, mut result, _) = self.factorize(rhs.to_vec());
+        for i in 0..self.n {
+            if lu.get(i, i) == K::zero() {
+                return Err("Matrix is singular");
+            }
+            if lu.get(i, i).may_be_zero() {
+                return Err("Matrix is possibly singular");
+            }
+        }
+
+        for i in (0..self.n).rev() {
+            let mut value = result[i];
+            let end = lu.band_cols(i).end;
+            for (j, &x) in result.iter().enumerate().take(end).skip(i + 1) {
+                value -= lu.get(i, j) * x;
+            }
+            result[i] = value / lu.get(i, i);
+        }
+
+        Ok(DVector::new(result))
+    }
+
+    pub fn determinant(&self) -> K {
+        let (lu, _, sign) = self.factorize(vec![K::zero(); self.n]);
+        (0..self.n).fold(sign, |acc, i| acc * lu.get(i, i))
+    }
+}
+
+impl<K: Ring> From<&Tridiagonal<K>> for Banded<K> {
+    fn from(matrix: &Tridiagonal<K>) -> Self {
+        let n = matrix.dim();
+        let mut result = Banded::zeros(n, 1, 1);
+        for i in 0..n {
+            result.set(i, i, matrix.diag[i]);
+            if i + 1 < n {
+                result.set(i + 1, i, matrix.lower[i]);
+                result.set(i, i + 1, matrix.upper[i]);
+            }
+        }
+        result
+    }
+}
+
+impl<K: Ring, const N: usize> TryFrom<&Tridiagonal<K>> for Matrix<K, N, N> {
+    type Error = &'static str;
+
+    fn try_from(matrix: &Tridiagonal<K>) -> Result<Self, Self::Error> {
+        Matrix::try_from(matrix.to_dense())
+    }
+}
+
+impl<K: Ring, const N: usize> TryFrom<&Banded<K>> for Matrix<K, N, N> {
+    type Error = &'static str;
+
+    fn try_from(matrix: &Banded<K>) -> Result<Self, Self::Error> {
+        Matrix::try_from(matrix.to_dense())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::Interval;
+    use approx::assert_abs_diff_eq;
+    use rand::prelude::*;
+
+    const NB_TESTCASE_MATRICES: usize = 100;
+    const THRESHOLD: f64 = 1e-8;
+
+    fn random_vec(n: usize) -> Vec<f64> {
+        let mut rng = rand::thread_rng();
+        (0..n).map(|_| rng.gen_range(-1.0..1.0)).collect()
+    }
+
+    fn generate_random_tridiagonal(n: usize) -> Tridiagonal<f64> {
+        let off = n.saturating_sub(1);
+        Tridiagonal::new(random_vec(off), random_vec(n), random_vec(off))
+    }
+
+    fn generate_random_banded(n: usize, kl: usize, ku: usize) -> Banded<f64> {
+        let mut rng = rand::thread_rng();
+        let mut result = Banded::zeros(n, kl, ku);
+        for i in 0..n {
+            for j in result.band_cols(i) {
+                result.set(i, j, rng.gen_range(-1.0..1.0));
+            }
+        }
+        result
+    }
+
+    fn assert_vec_eq(value: &[f64], expected: &[f64]) {
+        assert_eq!(value.len(), expected.len());
+        for (&a, &b) in value.iter().zip(expected) {
+            assert_abs_diff_eq!(a, b, epsilon = THRESHOLD);
+        }
+    }
+
+    #[test]
+    fn tridiagonal_against_dense() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let n = rng.gen_range(1..20);
+            let a = generate_random_tridiagonal(n);
+            let dense = a.to_dense();
+            let b = DVector::new(random_vec(n));
+
+            assert_vec_eq(&a.mul_vec(&b), &dense.mul_vec(&b));
+            assert_abs_diff_eq!(a.determinant(), dense.determinant(), epsilon = THRESHOLD);
+            if dense.determinant().abs() > 1e-3 {
+                let x = a.solve(&b).unwrap();
+                assert_vec_eq(&a.mul_vec(&x), &b);
+            }
+        }
+    }
+
+    #[test]
+    fn tridiagonal_exact() {
+        // Second-difference operator: det = n + 1.
+        let n = 6;
+        let a = Tridiagonal::new(vec![-1; n - 1], vec![2; n], vec![-1; n - 1]);
+        assert_eq!(a.determinant(), 7);
+        assert_eq!(
+            Matrix::<i64, 3, 3>::try_from(&Tridiagonal::new(vec![1, 2], vec![3, 4, 5], vec![6, 7])),
+            Ok(Matrix::new([[3, 6, 0], [1, 4, 7], [0, 2, 5]]))
+        );
+
+        // The Thomas pivot vanishes immediately, the pivoted fallback does not.
+        let swap = Tridiagonal::new(vec![1.], vec![0., 0.], vec![1.]);
+        assert_eq!(swap.solve(&[2., 3.]), Ok(DVector::new(vec![3., 2.])));
+        let singular = Tridiagonal::new(vec![1.], vec![1., 1.], vec![1.]);
+        assert_eq!(singular.solve(&[1., 1.]), Err("Matrix is singular"));
+    }
+
+    #[test]
+    fn banded_against_dense() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let n = rng.gen_range(1..20);
+            let (kl, ku) = (rng.gen_range(0..4), rng.gen_range(0..4));
+            let a = generate_random_banded(n, kl, ku);
+            let dense = a.to_dense();
+            let b = DVector::new(random_vec(n));
+
+            assert_eq!(Banded::from_dense(&dense, kl, ku), Ok(a.clone()));
+            assert_vec_eq(&a.mul_vec(&b), &dense.mul_vec(&b));
+            assert_abs_diff_eq!(a.determinant(), dense.determinant(), epsilon = THRESHOLD);
+            if dense.determinant().abs() > 1e-3 {
+                let x = a.solve(&b).unwrap();
+                assert_vec_eq(&a.mul_vec(&x), &b);
+            }
+        }
+    }
+
+    #[test]
+    fn banded_outside_band() {
+        let dense = DMatrix::from_rows(&[vec![1., 2., 3.], vec![0., 1., 2.], vec![0., 0., 1.]]);
+        assert!(Banded::from_dense(&dense, 0, 1).is_err());
+        assert_eq!(Banded::from_dense(&dense, 0, 2).unwrap().determinant(), 1.);
+    }
+
+    #[test]
+    fn banded_interval_determinant() {
+        // The leading pivot straddles zero, yet the determinant, 3·x - 2 for
+        // x in [0, 2], must still be enclosed.
+        let x = Interval::new(0., 2.);
+        let dense = DMatrix::from_rows(&[
+            vec![x, Interval::point(1.), Interval::point(0.)],
+            vec![
+                Interval::point(1.),
+                Interval::point(2.),
+                Interval::point(1.),
+            ],
+            vec![
+                Interval::point(0.),
+                Interval::point(1.),
+                Interval::point(2.),
+            ],
+        ]);
+        let banded = Banded::from_dense(&dense, 1, 1).unwrap();
+        let determinant = banded.determinant();
+        for t in [0., 0.5, 1., 1.5, 2.] {
+            assert!(determinant.contains(3. * t - 2.));
+        }
+        assert_eq!(
+            banded.solve(&[Interval::point(1.); 3]),
+            Err("Matrix is possibly singular")
+        );
+
+        let singular = Banded::from_dense(&DMatrix::from_rows(&[vec![0., 1.], vec![0., 2.]]), 1, 1);
+        assert_eq!(singular.clone().unwrap().determinant(), 0.);
+        assert_eq!(
+            singular.unwrap().solve(&[1., 1.]),
+            Err("Matrix is singular")
+        );
+    }
+}
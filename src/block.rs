@@ -0,0 +1,233 @@
+use crate::field::Ring;
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+
+// Stable const generics cannot name `N + P` in a return type, so the output
+// dimensions are extra parameters checked against the inputs at compile time.
+
+impl<K: Ring, const M: usize, const N: usize> Matrix<K, M, N> {
+    /// `[self | rhs]`.
+    pub fn hstack<const P: usize, const Q: usize>(&self, rhs: &Matrix<K, M, P>) -> Matrix<K, M, Q> {
+        const { assert!(Q == N + P, "hstack output must have N + P columns") };
+        let mut data = [[K::zero(); Q]; M];
+        for (row, (lhs, rhs)) in data.iter_mut().zip(self.iter().zip(rhs.iter())) {
+            row[..N].copy_from_slice(lhs);
+            row[N..].copy_from_slice(rhs);
+        }
+        Matrix::new(data)
+    }
+
+    /// `self` on top of `rhs`.
+    pub fn vstack<const P: usize, const Q: usize>(&self, rhs: &Matrix<K, P, N>) -> Matrix<K, Q, N> {
+        const { assert!(Q == M + P, "vstack output must have M + P rows") };
+        let mut data = [[K::zero(); N]; Q];
+        data[..M].copy_from_slice(&self[..]);
+        data[M..].copy_from_slice(&rhs[..]);
+        Matrix::new(data)
+    }
+
+    /// The augmented matrix `[self | b]` of the system `self · x = b`.
+    pub fn augment<const Q: usize>(&self, b: &Vector<K, M>) -> Matrix<K, M, Q> {
+        const { assert!(Q == N + 1, "augment output must have N + 1 columns") };
+        let mut data = [[K::zero(); Q]; M];
+        for (i, row) in data.iter_mut().enumerate() {
+            row[..N].copy_from_slice(&self[i]);
+            row[N] = b[i];
+        }
+        Matrix::new(data)
+    }
+
+    /// `[[self, 0], [0, rhs]]`.
+    pub fn block_diag<const P: usize, const R: usize, const S: usize, const T: usize>(
+        &self,
+        rhs: &Matrix<K, P, R>,
+    ) -> Matrix<K, S, T> {
+        const {
+            assert!(
+                S == M + P && T == N + R,
+                "block_diag output must be (M + P) x (N + R)"
+            )
+        };
+        let mut data = [[K::zero(); T]; S];
+        for (row, lhs) in data.iter_mut().zip(self.iter()) {
+            row[..N].copy_from_slice(lhs);
+        }
+        for (row, rhs) in data[M..].iter_mut().zip(rhs.iter()) {
+            row[N..].copy_from_slice(rhs);
+        }
+        Matrix::new(data)
+    }
+
+    /// Inverse of `hstack`: the first `P` columns and the remaining `Q`.
+    pub fn split_columns<const P: usize, const Q: usize>(
+        &self,
+    ) -> (Matrix<K, M, P>, Matrix<K, M, Q>) {
+        const { assert!(P + Q == N, "split_columns parts must add up to N columns") };
+        let mut left = [[K::zero(); P]; M];
+        let mut right = [[K::zero(); Q]; M];
+        for (i, row) in self.iter().enumerate() {
+            left[i].copy_from_slice(&row[..P]);
+            right[i].copy_from_slice(&row[P..]);
+        }
+        (Matrix::new(left), Matrix::new(right))
+    }
+
+    /// Inverse of `vstack`: the first `P` rows and the remaining `Q`.
+    pub fn split_rows<const P: usize, const Q: usize>(&self) -> (Matrix<K, P, N>, Matrix<K, Q, N>) {
+        const { assert!(P + Q == M, "split_rows parts must add up to M rows") };
+        let mut top = [[K::zero(); N]; P];
+        let mut bottom = [[K::zero(); N]; Q];
+        top.copy_from_slice(&self[..P]);
+        bottom.copy_from_slice(&self[P..]);
+        (Matrix::new(top), Matrix::new(bottom))
+    }
+
+    /// Inverse of `augment`: the coefficient matrix and the last column.
+    pub fn split_augmented<const P: usize>(&self) -> (Matrix<K, M, P>, Vector<K, M>) {
+        const {
+            assert!(
+                P + 1 == N,
+                "split_augmented expects N - 1 coefficient columns"
+            )
+        };
+        let (coefficients, last) = self.split_columns::<P, 1>();
+        (coefficients, Vector::new(last.map(|row| row[0])))
+    }
+}
+
+/// A matrix partitioned as `[[a11, a12], [a21, a22]]`, with `a11` of size
+/// `M1 × N1`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Blocks<K, const M1: usize, const M2: usize, const N1: usize, const N2: usize> {
+    pub a11: Matrix<K, M1, N1>,
+    pub a12: Matrix<K, M1, N2>,
+    pub a21: Matrix<K, M2, N1>,
+    pub a22: Matrix<K, M2, N2>,
+}
+
+impl<K: Ring, const M1: usize, const M2: usize, const N1: usize, const N2: usize>
+    Blocks<K, M1, M2, N1, N2>
+{
+    pub fn new(
+        a11: Matrix<K, M1, N1>,
+        a12: Matrix<K, M1, N2>,
+        a21: Matrix<K, M2, N1>,
+        a22: Matrix<K, M2, N2>,
+    ) -> Self {
+        Blocks { a11, a12, a21, a22 }
+    }
+
+    pub fn split<const M: usize, const N: usize>(matrix: &Matrix<K, M, N>) -> Self {
+        let (top, bottom) = matrix.split_rows::<M1, M2>();
+        let (a11, a12) = top.split_columns::<N1, N2>();
+        let (a21, a22) = bottom.split_columns::<N1, N2>();
+        Blocks { a11, a12, a21, a22 }
+    }
+
+    pub fn assemble<const M: usize, const N: usize>(&self) -> Matrix<K, M, N> {
+        let top: Matrix<K, M1, N> = self.a11.hstack(&self.a12);
+        let bottom: Matrix<K, M2, N> = self.a21.hstack(&self.a22);
+        top.vstack(&bottom)
+    }
+
+    /// Multiplies block by block, e.g. `c11 = a11·b11 + a12·b21`. The column
+    /// partition of `self` must match the row partition of `rhs`.
+    pub fn mul_blocks<const P1: usize, const P2: usize>(
+        &self,
+        rhs: &Blocks<K, N1, N2, P1, P2>,
+    ) -> Blocks<K, M1, M2, P1, P2> {
+        Blocks {
+            a11: self.a11.mul_mat(&rhs.a11) + self.a12.mul_mat(&rhs.a21),
+            a12: self.a11.mul_mat(&rhs.a12) + self.a12.mul_mat(&rhs.a22),
+            a21: self.a21.mul_mat(&rhs.a11) + self.a22.mul_mat(&rhs.a21),
+            a22: self.a21.mul_mat(&rhs.a12) + self.a22.mul_mat(&rhs.a22),
+        }
+    }
+
+    pub fn transpose(&self) -> Blocks<K, N1, N2, M1, M2> {
+        Blocks {
+            a11: self.a11.transpose(),
+            a12: self.a21.transpose(),
+            a21: self.a12.transpose(),
+            a22: self.a22.transpose(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use rand::prelude::*;
+
+    const NB_TESTCASE_MATRICES: usize = 100;
+    const THRESHOLD: f64 = 1e-8;
+
+    fn generate_random_matrix<const M: usize, const N: usize>() -> Matrix<i64, M, N> {
+        let mut rng = rand::thread_rng();
+        let mut data = [[0; N]; M];
+        for row in &mut data {
+            for elem in row {
+                *elem = rng.gen_range(-10..10);
+            }
+        }
+        Matrix::new(data)
+    }
+
+    #[test]
+    fn block_stacking() {
+        let a = Matrix::new([[1, 2], [3, 4]]);
+        let b = Matrix::new([[5], [6]]);
+        let c = Matrix::new([[7, 8]]);
+
+        let ab: Matrix<i64, 2, 3> = a.hstack(&b);
+        assert_eq!(ab, Matrix::new([[1, 2, 5], [3, 4, 6]]));
+        let ac: Matrix<i64, 3, 2> = a.vstack(&c);
+        assert_eq!(ac, Matrix::new([[1, 2], [3, 4], [7, 8]]));
+        let diag: Matrix<i64, 3, 4> = a.block_diag(&c);
+        assert_eq!(
+            diag,
+            Matrix::new([[1, 2, 0, 0], [3, 4, 0, 0], [0, 0, 7, 8]])
+        );
+
+        assert_eq!(ab.split_columns::<2, 1>(), (a, b));
+        assert_eq!(ac.split_rows::<2, 1>(), (a, c));
+    }
+
+    #[test]
+    fn block_augmented_row_echelon() {
+        let a = Matrix::new([[8., 5., -2., 4.], [4., 2.5, 20., 4.], [8., 5., 1., 4.]]);
+        let b = Vector::new([28., -4., 17.]);
+
+        let system: Matrix<f64, 3, 5> = a.augment(&b);
+        assert_eq!(
+            system,
+            Matrix::new([
+                [8., 5., -2., 4., 28.],
+                [4., 2.5, 20., 4., -4.],
+                [8., 5., 1., 4., 17.],
+            ])
+        );
+        assert_eq!(system.split_augmented::<4>(), (a, b));
+
+        let (reduced, rhs) = system.row_echelon().split_augmented::<4>();
+        assert_abs_diff_eq!(reduced[2][3], 1., epsilon = THRESHOLD);
+        assert_abs_diff_eq!(rhs[2], 29.5, epsilon = THRESHOLD);
+    }
+
+    #[test]
+    fn block_multiplication() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let a = generate_random_matrix::<5, 4>();
+            let b = generate_random_matrix::<4, 6>();
+
+            let blocks_a = Blocks::<i64, 2, 3, 1, 3>::split(&a);
+            let blocks_b = Blocks::<i64, 1, 3, 4, 2>::split(&b);
+            assert_eq!(blocks_a.assemble::<5, 4>(), a);
+
+            let product: Matrix<i64, 5, 6> = blocks_a.mul_blocks(&blocks_b).assemble();
+            assert_eq!(product, a.mul_mat(&b));
+            assert_eq!(blocks_a.transpose().assemble::<4, 5>(), a.transpose());
+        }
+    }
+}
@@ -0,0 +1,471 @@
+use crate::field::Real;
+use crate::matrix::Matrix;
+use crate::structured::{LowerTriangular, Symmetric};
+use crate::vector::Vector;
+
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CholeskyError {
+    /// The leading minor of this order (1-based) is not positive.
+    NotPositiveDefinite(usize),
+}
+
+impl Display for CholeskyError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            CholeskyError::NotPositiveDefinite(order) => write!(
+                f,
+                "Matrix is not positive definite (leading minor of order {order})"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CholeskyError {}
+
+/// `A = L·Lᵀ` for a symmetric positive definite `A`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cholesky<K, const N: usize> {
+    l: LowerTriangular<K, N>,
+}
+
+impl<K: Real, const N: usize> Cholesky<K, N> {
+    /// Only the lower triangle of `matrix` is read.
+    pub fn new(matrix: &Matrix<K, N, N>) -> Result<Self, CholeskyError> {
+        let mut l = LowerTriangular::zeros();
+        for j in 0..N {
+            let diag = (0..j).fold(matrix[j][j], |acc, k| acc - l.get(j, k) * l.get(j, k));
+            // Also rejects NaN and intervals straddling zero.
+            if diag.partial_cmp(&K::zero()) != Some(Ordering::Greater) {
+                return Err(CholeskyError::NotPositiveDefinite(j + 1));
+            }
+            let diag = diag.sqrt();
+            l.set(j, j, diag);
+            for i in (j + 1)..N {
+                let value = (0..j).fold(matrix[i][j], |acc, k| acc - l.get(i, k) * l.get(j, k));
+                l.set(i, j, value / diag);
+            }
+        }
+        Ok(Cholesky { l })
+    }
+
+    pub fn l(&self) -> &LowerTriangular<K, N> {
+        &self.l
+    }
+
+    pub fn solve(&self, rhs: &Vector<K, N>) -> Vector<K, N> {
+        let mut result = *rhs;
+        for i in 0..N {
+            let value = (0..i).fold(result[i], |acc, k| acc - self.l.get(i, k) * result[k]);
+            result[i] = value / self.l.get(i, i);
+        }
+        for i in (0..N).rev() {
+            let value = ((i + 1)..N).fold(result[i], |acc, k| acc - self.l.get(k, i) * result[k]);
+            result[i] = value / self.l.get(i, i);
+        }
+        result
+    }
+
+    pub fn determinant(&self) -> K {
+        (0..N).fold(K::one(), |acc, i| acc * self.l.get(i, i) * self.l.get(i, i))
+    }
+
+    /// `ln det A`, without the overflow of the determinant itself.
+    pub fn log_determinant(&self) -> K {
+        let two = K::one() + K::one();
+        (0..N).fold(K::zero(), |acc, i| acc + two * self.l.get(i, i).ln())
+    }
+
+    pub fn inverse(&self) -> Matrix<K, N, N> {
+        let mut columns = Matrix::<K, N, N>::identity();
+        for column in columns.iter_mut() {
+            let solution = self.solve(&Vector::new(*column));
+            column.copy_from_slice(&solution);
+        }
+        // The inverse is symmetric, so its rows are its columns.
+        columns
+    }
+}
+
+/// Bunch-Kaufman factorization `P·A·Pᵀ = L·D·Lᵀ` of a symmetric, possibly
+/// indefinite `A`: `L` is unit lower triangular and `D` block diagonal with
+/// 1×1 and 2×2 blocks. Row `i` of `P·A·Pᵀ` is row `permutation[i]` of `A`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LDL<K, const N: usize> {
+    l: Matrix<K, N, N>,
+    diagonal: [K; N],
+    /// `off_diagonal[k]` couples `k` and `k + 1` in a 2×2 block, else zero.
+    off_diagonal: [K; N],
+    permutation: [usize; N],
+}
+
+impl<K: Real, const N: usize> LDL<K, N> {
+    /// Only the lower triangle of `matrix` is read.
+    pub fn new(matrix: &Matrix<K, N, N>) -> Self {
+        let mut packed = Symmetric::zeros();
+        for i in 0..N {
+            for j in 0..=i {
+                packed.set(i, j, matrix[i][j]);
+            }
+        }
+        Self::from_packed(packed)
+    }
+
+    /// Factorizes in place on the packed lower triangle, so each step only
+    /// updates half of the trailing block.
+    pub(crate) fn from_packed(mut a: Symmetric<K, N>) -> Self {
+        let alpha = (K::one() + K::from_f64(17.).sqrt()) / K::from_f64(8.);
+        let mut l = Matrix::<K, N, N>::identity();
+        let mut diagonal = [K::zero(); N];
+        let mut off_diagonal = [K::zero(); N];
+        let mut permutation = std::array::from_fn(|i| i);
+
+        let mut k = 0;
+        while k < N {
+            let absakk = a.get(k, k).abs();
+            let mut r = k;
+            let mut colmax = K::zero();
+            for i in (k + 1)..N {
+                if a.get(i, k).abs() > colmax {
+                    r = i;
+                    colmax = a.get(i, k).abs();
+                }
+            }
+
+            let mut size = 1;
+            if colmax > K::zero() && absakk < alpha * colmax {
+                let rowmax = (k..N)
+                    .filter(|&j| j != r)
+                    .map(|j| a.get(r, j).abs())
+                    .fold(K::zero(), |acc, x| if x > acc { x } else { acc });
+                // Otherwise `a[k][k]` is large enough against both maxima.
+                if absakk * rowmax < alpha * colmax * colmax {
+                    if a.get(r, r).abs() >= alpha * rowmax {
+                        Self::swap(&mut a, &mut l, &mut permutation, k, k, r);
+                    } else {
+                        Self::swap(&mut a, &mut l, &mut permutation, k, k + 1, r);
+                        size = 2;
+                    }
+                }
+            }
+
+            if size == 1 {
+                let d = a.get(k, k);
+                diagonal[k] = d;
+                if d != K::zero() {
+                    for i in (k + 1)..N {
+                        l[i][k] = a.get(i, k) / d;
+                        for j in (k + 1)..=i {
+                            let value = a.get(i, j) - l[i][k] * a.get(j, k);
+                            a.set(i, j, value);
+                        }
+                    }
+                }
+            } else {
+                let (d11, d21, d22) = (a.get(k, k), a.get(k + 1, k), a.get(k + 1, k + 1));
+                let det = d11 * d22 - d21 * d21;
+                diagonal[k] = d11;
+                diagonal[k + 1] = d22;
+                off_diagonal[k] = d21;
+                for i in (k + 2)..N {
+                    let (x, y) = (a.get(i, k), a.get(i, k + 1));
+                    l[i][k] = (x * d22 - y * d21) / det;
+                    l[i][k + 1] = (y * d11 - x * d21) / det;
+                }
+                for i in (k + 2)..N {
+                    for j in (k + 2)..=i {
+                        let value =
+                            a.get(i, j) - l[i][k] * a.get(j, k) - l[i][k + 1] * a.get(j, k + 1);
+                        a.set(i, j, value);
+                    }
+                }
+            }
+            k += size;
+        }
+
+        LDL {
+            l,
+            diagonal,
+            off_diagonal,
+            permutation,
+        }
+    }
+
+    /// Symmetric swap of indices `p` and `q`, both at least `k`; the
+    /// finished columns of `l` follow the rows.
+    fn swap(
+        a: &mut Symmetric<K, N>,
+        l: &mut Matrix<K, N, N>,
+        permutation: &mut [usize; N],
+        k: usize,
+        p: usize,
+        q: usize,
+    ) {
+        if p == q {
+            return;
+        }
+        a.swap_symmetric(p, q);
+        for j in 0..k {
+            let value = l[p][j];
+            l[p][j] = l[q][j];
+            l[q][j] = value;
+        }
+        permutation.swap(p, q);
+    }
+
+    pub fn l(&self) -> LowerTriangular<K, N> {
+        LowerTriangular::try_from(&self.l).unwrap()
+    }
+
+    /// The block diagonal factor as a dense matrix.
+    pub fn d(&self) -> Matrix<K, N, N> {
+        let mut result = Matrix::new([[K::zero(); N]; N]);
+        for i in 0..N {
+            result[i][i] = self.diagonal[i];
+            if i + 1 < N {
+                result[i + 1][i] = self.off_diagonal[i];
+                result[i][i + 1] = self.off_diagonal[i];
+            }
+        }
+        result
+    }
+
+    pub fn permutation(&self) -> &[usize; N] {
+        &self.permutation
+    }
+
+    pub fn p(&self) -> Matrix<K, N, N> {
+        let mut data = [[K::zero(); N]; N];
+        for (row, &col) in data.iter_mut().zip(&self.permutation) {
+            row[col] = K::one();
+        }
+        Matrix::new(data)
+    }
+
+    pub fn determinant(&self) -> K {
+        let mut result = K::one();
+        let mut k = 0;
+        while k < N {
+            if k + 1 < N && self.off_diagonal[k] != K::zero() {
+                let (d11, d21, d22) =
+                    (self.diagonal[k], self.off_diagonal[k], self.diagonal[k + 1]);
+                result *= d11 * d22 - d21 * d21;
+                k += 2;
+            } else {
+                result *= self.diagonal[k];
+                k += 1;
+            }
+        }
+        result
+    }
+
+    pub fn solve(&self, rhs: &Vector<K, N>) -> Result<Vector<K, N>, &'static str> {
+        let mut y = Vector::new(self.permutation.map(|i| rhs[i]));
+        for i in 0..N {
+            for j in 0..i {
+                let value = y[j];
+                y[i] -= self.l[i][j] * value;
+            }
+        }
+
+        let mut k = 0;
+        while k < N {
+            if k + 1 < N && self.off_diagonal[k] != K::zero() {
+                let (d11, d21, d22) =
+                    (self.diagonal[k], self.off_diagonal[k], self.diagonal[k + 1]);
+                let det = d11 * d22 - d21 * d21;
+                if det == K::zero() {
+                    return Err("Matrix is singular");
+                }
+                let (x, z) = (y[k], y[k + 1]);
+                y[k] = (x * d22 - z * d21) / det;
+                y[k + 1] = (z * d11 - x * d21) / det;
+                k += 2;
+            } else {
+                if self.diagonal[k] == K::zero() {
+                    return Err("Matrix is singular");
+                }
+                y[k] /= self.diagonal[k];
+                k += 1;
+            }
+        }
+
+        for i in (0..N).rev() {
+            for j in (i + 1)..N {
+                let value = y[j];
+                y[i] -= self.l[j][i] * value;
+            }
+        }
+
+        let mut result = Vector::new([K::zero(); N]);
+        for (i, &row) in self.permutation.iter().enumerate() {
+            result[row] = y[i];
+        }
+        Ok(result)
+    }
+
+    pub fn inverse(&self) -> Result<Matrix<K, N, N>, &'static str> {
+        let mut columns = Matrix::<K, N, N>::identity();
+        for column in columns.iter_mut() {
+            let solution = self.solve(&Vector::new(*column))?;
+            column.copy_from_slice(&solution);
+        }
+        Ok(columns)
+    }
+}
+
+impl<K: Real, const N: usize> Matrix<K, N, N> {
+    pub fn cholesky(&self) -> Result<Cholesky<K, N>, CholeskyError> {
+        Cholesky::new(self)
+    }
+
+    pub fn ldl(&self) -> LDL<K, N> {
+        LDL::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use nalgebra::DMatrix;
+    use rand::prelude::*;
+
+    const NB_TESTCASE_MATRICES: usize = 100;
+    const THRESHOLD: f64 = 1e-8;
+
+    fn generate_random_matrix<const N: usize>() -> Matrix<f64, N, N> {
+        let mut rng = rand::thread_rng();
+        let mut data = [[0.0; N]; N];
+        for row in &mut data {
+            for elem in row {
+                *elem = rng.gen_range(-1.0..1.0);
+            }
+        }
+        Matrix::new(data)
+    }
+
+    fn generate_random_symmetric<const N: usize>() -> Matrix<f64, N, N> {
+        let a = generate_random_matrix::<N>();
+        a + a.transpose()
+    }
+
+    fn assert_matrix_eq<const N: usize>(matrix: &Matrix<f64, N, N>, expected: &DMatrix<f64>) {
+        for i in 0..N {
+            for j in 0..N {
+                assert_abs_diff_eq!(matrix[i][j], expected[(i, j)], epsilon = 1e-6);
+            }
+        }
+    }
+
+    fn test_cholesky<const N: usize>() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let b = generate_random_matrix::<N>();
+            let a = b.transpose().mul_mat(&b) + Matrix::<f64, N, N>::identity();
+            let nalgebra_a = DMatrix::from_fn(N, N, |i, j| a[i][j]);
+            let expected = nalgebra_a.clone().cholesky().unwrap();
+
+            let cholesky = a.cholesky().unwrap();
+            assert_matrix_eq(&Matrix::from(cholesky.l()), &expected.l());
+            assert_abs_diff_eq!(
+                cholesky.determinant(),
+                nalgebra_a.determinant(),
+                epsilon = 1e-6
+            );
+            assert_abs_diff_eq!(
+                cholesky.log_determinant(),
+                nalgebra_a.determinant().ln(),
+                epsilon = 1e-6
+            );
+            assert_matrix_eq(&cholesky.inverse(), &expected.inverse());
+
+            let rhs = Vector::new(generate_random_matrix::<N>()[0]);
+            let x = a.mul_vec(&cholesky.solve(&rhs));
+            for i in 0..N {
+                assert_abs_diff_eq!(x[i], rhs[i], epsilon = THRESHOLD);
+            }
+        }
+    }
+
+    fn test_ldl<const N: usize>() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let a = generate_random_symmetric::<N>();
+            let nalgebra_a = DMatrix::from_fn(N, N, |i, j| a[i][j]);
+            let ldl = a.ldl();
+
+            let l = Matrix::from(ldl.l());
+            let p = ldl.p();
+            let expected = p.mul_mat(&a).mul_mat(&p.transpose());
+            let product = l.mul_mat(&ldl.d()).mul_mat(&l.transpose());
+            for i in 0..N {
+                for j in 0..N {
+                    assert_abs_diff_eq!(product[i][j], expected[i][j], epsilon = THRESHOLD);
+                }
+            }
+            assert_abs_diff_eq!(ldl.determinant(), nalgebra_a.determinant(), epsilon = 1e-6);
+
+            if nalgebra_a.determinant().abs() < 1e-3 {
+                continue;
+            }
+            assert_matrix_eq(&ldl.inverse().unwrap(), &nalgebra_a.try_inverse().unwrap());
+            let rhs = Vector::new(generate_random_matrix::<N>()[0]);
+            let x = a.mul_vec(&ldl.solve(&rhs).unwrap());
+            for i in 0..N {
+                assert_abs_diff_eq!(x[i], rhs[i], epsilon = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn cholesky_4() {
+        test_cholesky::<4>();
+    }
+
+    #[test]
+    fn cholesky_8() {
+        test_cholesky::<8>();
+    }
+
+    #[test]
+    fn cholesky_not_positive_definite() {
+        let indefinite = Matrix::new([[4., 2., 0.], [2., 1., 0.], [0., 0., 1.]]);
+        assert_eq!(
+            indefinite.cholesky(),
+            Err(CholeskyError::NotPositiveDefinite(2))
+        );
+        let negative = Matrix::new([[-1., 0.], [0., 1.]]);
+        assert_eq!(
+            negative.cholesky().unwrap_err().to_string(),
+            "Matrix is not positive definite (leading minor of order 1)"
+        );
+    }
+
+    #[test]
+    fn ldl_4() {
+        test_ldl::<4>();
+    }
+
+    #[test]
+    fn ldl_8() {
+        test_ldl::<8>();
+    }
+
+    #[test]
+    fn ldl_indefinite() {
+        // No 1×1 pivot works here; a single 2×2 block does.
+        let swap = Matrix::new([[0., 1.], [1., 0.]]);
+        let ldl = swap.ldl();
+        assert_eq!(ldl.d(), swap);
+        assert_eq!(ldl.determinant(), -1.);
+        assert_eq!(ldl.inverse(), Ok(swap));
+
+        let singular = Matrix::new([[1., 2.], [2., 4.]]);
+        assert_eq!(singular.ldl().determinant(), 0.);
+        assert_eq!(
+            singular.ldl().solve(&Vector::new([1., 1.])),
+            Err("Matrix is singular")
+        );
+    }
+}
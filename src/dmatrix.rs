@@ -0,0 +1,544 @@
+use crate::dvector::DVector;
+use crate::field::{Field, Ring};
+use crate::lu;
+use crate::matrix::{bareiss_determinant, row_echelon, Matrix};
+use crate::view::VectorAccess;
+
+use std::fmt::{Display, Formatter, Result};
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DMatrix<K> {
+    data: Vec<K>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<K: Ring + Display> Display for DMatrix<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "[")?;
+        for i in 0..self.rows {
+            if i != 0 {
+                write!(f, "\n ")?;
+            }
+            write!(f, "[")?;
+            for (j, item) in self[i].iter().enumerate() {
+                if j != 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{:.3}", item)?;
+            }
+            write!(f, "]")?;
+        }
+        writeln!(f, "]")
+    }
+}
+
+impl<K> Index<usize> for DMatrix<K> {
+    type Output = [K];
+
+    fn index(&self, row: usize) -> &Self::Output {
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+}
+
+impl<K> IndexMut<usize> for DMatrix<K> {
+    fn index_mut(&mut self, row: usize) -> &mut Self::Output {
+        &mut self.data[row * self.cols..(row + 1) * self.cols]
+    }
+}
+
+impl<K: Ring> Add<DMatrix<K>> for DMatrix<K> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut data = self;
+        data.operate(&rhs, |a, b| a + b);
+
+        data
+    }
+}
+
+impl<K: Ring> Add<DVector<K>> for DMatrix<K> {
+    type Output = Self;
+
+    fn add(self, rhs: DVector<K>) -> Self::Output {
+        let mut result = self;
+        result.operate_vec(&rhs, |a, b| a + b);
+
+        result
+    }
+}
+
+impl<K: Ring> Add<K> for DMatrix<K> {
+    type Output = Self;
+
+    fn add(self, scalar: K) -> Self::Output {
+        let mut result = self;
+        result.operate_scalar(scalar, |a, b| a + b);
+
+        result
+    }
+}
+
+impl<K: Ring> AddAssign<DMatrix<K>> for DMatrix<K> {
+    fn add_assign(&mut self, rhs: DMatrix<K>) {
+        self.operate(&rhs, |a, b| a + b);
+    }
+}
+
+impl<K: Ring> AddAssign<DVector<K>> for DMatrix<K> {
+    fn add_assign(&mut self, rhs: DVector<K>) {
+        self.operate_vec(&rhs, |a, b| a + b);
+    }
+}
+
+impl<K: Ring> AddAssign<K> for DMatrix<K> {
+    fn add_assign(&mut self, scalar: K) {
+        self.operate_scalar(scalar, |a, b| a + b);
+    }
+}
+
+impl<K: Ring> Sub<DMatrix<K>> for DMatrix<K> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut data = self;
+        data.operate(&rhs, |a, b| a - b);
+
+        data
+    }
+}
+
+impl<K: Ring> Sub<DVector<K>> for DMatrix<K> {
+    type Output = Self;
+
+    fn sub(self, rhs: DVector<K>) -> Self::Output {
+        let mut result = self;
+        result.operate_vec(&rhs, |a, b| a - b);
+
+        result
+    }
+}
+
+impl<K: Ring> Sub<K> for DMatrix<K> {
+    type Output = Self;
+
+    fn sub(self, scalar: K) -> Self::Output {
+        let mut result = self;
+        result.operate_scalar(scalar, |a, b| a - b);
+
+        result
+    }
+}
+
+impl<K: Ring> SubAssign<DMatrix<K>> for DMatrix<K> {
+    fn sub_assign(&mut self, rhs: DMatrix<K>) {
+        self.operate(&rhs, |a, b| a - b);
+    }
+}
+
+impl<K: Ring> SubAssign<DVector<K>> for DMatrix<K> {
+    fn sub_assign(&mut self, rhs: DVector<K>) {
+        self.operate_vec(&rhs, |a, b| a - b);
+    }
+}
+
+impl<K: Ring> SubAssign<K> for DMatrix<K> {
+    fn sub_assign(&mut self, scalar: K) {
+        self.operate_scalar(scalar, |a, b| a - b);
+    }
+}
+
+impl<K: Ring> Mul<DMatrix<K>> for DMatrix<K> {
+    type Output = DMatrix<K>;
+
+    fn mul(self, rhs: DMatrix<K>) -> Self::Output {
+        self.mul_mat(&rhs)
+    }
+}
+
+impl<K: Ring> Mul<DVector<K>> for DMatrix<K> {
+    type Output = DVector<K>;
+
+    fn mul(self, rhs: DVector<K>) -> Self::Output {
+        self.mul_vec(&rhs)
+    }
+}
+
+impl<K: Ring> Mul<K> for DMatrix<K> {
+    type Output = Self;
+
+    fn mul(self, scalar: K) -> Self::Output {
+        let mut result = self;
+        result.operate_scalar(scalar, |a, b| a * b);
+
+        result
+    }
+}
+
+impl<K: Ring> MulAssign<DMatrix<K>> for DMatrix<K> {
+    fn mul_assign(&mut self, rhs: DMatrix<K>) {
+        *self = self.mul_mat(&rhs);
+    }
+}
+
+impl<K: Ring> MulAssign<K> for DMatrix<K> {
+    fn mul_assign(&mut self, scalar: K) {
+        self.operate_scalar(scalar, |a, b| a * b);
+    }
+}
+
+impl<K: Field> Div<K> for DMatrix<K> {
+    type Output = Self;
+
+    fn div(self, scalar: K) -> Self::Output {
+        let mut result = self;
+        result.operate_scalar(scalar, |a, b| a / b);
+
+        result
+    }
+}
+
+impl<K: Field> DivAssign<K> for DMatrix<K> {
+    fn div_assign(&mut self, scalar: K) {
+        self.operate_scalar(scalar, |a, b| a / b);
+    }
+}
+
+impl<K: Ring> DMatrix<K> {
+    pub fn new(rows: usize, cols: usize, data: Vec<K>) -> Self {
+        assert_eq!(
+            rows * cols,
+            data.len(),
+            "Number of elements must match the matrix dimensions"
+        );
+        DMatrix { data, rows, cols }
+    }
+
+    pub fn from_rows(rows: &[Vec<K>]) -> Self {
+        let cols = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "All rows must have the same length"
+        );
+        DMatrix {
+            data: rows.concat(),
+            rows: rows.len(),
+            cols,
+        }
+    }
+
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        DMatrix {
+            data: vec![K::zero(); rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    pub fn identity(n: usize) -> Self {
+        let mut result = Self::zeros(n, n);
+        for i in 0..n {
+            result[i][i] = K::one();
+        }
+        result
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn as_slice(&self) -> &[K] {
+        &self.data
+    }
+
+    fn operate<F: Fn(K, K) -> K>(&mut self, v: &DMatrix<K>, op: F) {
+        assert_eq!(
+            (self.rows, self.cols),
+            (v.rows, v.cols),
+            "Matrix dimensions must match"
+        );
+        self.data
+            .iter_mut()
+            .zip(&v.data)
+            .for_each(|(a, b)| *a = op(*a, *b));
+    }
+
+    fn operate_vec<F: Fn(K, K) -> K>(&mut self, v: &DVector<K>, op: F) {
+        assert_eq!(self.cols, v.dim(), "Vector dimension must match columns");
+        self.data.chunks_mut(self.cols.max(1)).for_each(|row| {
+            row.iter_mut()
+                .zip(v.iter())
+                .for_each(|(a, &b)| *a = op(*a, b))
+        });
+    }
+
+    fn operate_scalar<F: Fn(K, K) -> K>(&mut self, scalar: K, op: F) {
+        self.data.iter_mut().for_each(|elt| *elt = op(*elt, scalar));
+    }
+
+    pub fn add(&mut self, v: &DMatrix<K>) {
+        self.operate(v, |a, b| a + b);
+    }
+
+    pub fn sub(&mut self, v: &DMatrix<K>) {
+        self.operate(v, |a, b| a - b);
+    }
+
+    pub fn scl(&mut self, scalar: K) {
+        self.operate_scalar(scalar, |a, b| a * b);
+    }
+
+    pub fn mul_mat(&self, rhs: &DMatrix<K>) -> DMatrix<K> {
+        assert_eq!(self.cols, rhs.rows, "Matrix dimensions are incompatible");
+        let mut result = Self::zeros(self.rows, rhs.cols);
+        for i in 0..self.rows {
+            for (k, &a) in self[i].iter().enumerate() {
+                for (elt, &b) in result[i].iter_mut().zip(&rhs[k]) {
+                    *elt += a * b;
+                }
+            }
+        }
+        result
+    }
+
+    pub fn mul_vec<V: VectorAccess<K> + ?Sized>(&self, rhs: &V) -> DVector<K> {
+        assert_eq!(self.cols, rhs.dim(), "Vector dimension must match columns");
+        DVector::new(
+            (0..self.rows)
+                .map(|i| {
+                    self[i]
+                        .iter()
+                        .enumerate()
+                        .fold(K::zero(), |acc, (j, &a)| acc + a * rhs.at(j))
+                })
+                .collect(),
+        )
+    }
+
+    pub fn transpose(&self) -> DMatrix<K> {
+        let mut result = Self::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for (j, &elt) in self[i].iter().enumerate() {
+                result[j][i] = elt;
+            }
+        }
+        result
+    }
+
+    pub fn conjugate_transpose(&self) -> DMatrix<K> {
+        let mut result = self.transpose();
+        result.data.iter_mut().for_each(|x| *x = x.conjugate());
+        result
+    }
+
+    pub fn trace(&self) -> K {
+        assert_eq!(self.rows, self.cols, "Matrix must be square");
+        (0..self.rows).fold(K::zero(), |sum, i| sum + self[i][i])
+    }
+}
+
+impl<K: Ring + Div<Output = K>> DMatrix<K> {
+    /// Fraction-free Bareiss elimination: every division is exact, so integer
+    /// matrices never leave the ring.
+    pub fn determinant(&self) -> K {
+        assert_eq!(self.rows, self.cols, "Matrix must be square");
+        bareiss_determinant(&self.data, self.rows)
+    }
+}
+
+impl<K: Field> DMatrix<K> {
+    pub fn inv_scl(&mut self, scalar: K) {
+        self.operate_scalar(scalar, |a, b| a / b);
+    }
+
+    pub fn row_echelon(&self) -> Self {
+        self.row_echelon_permuted().0
+    }
+
+    /// `row_echelon` together with the row order: row `i` of the result was
+    /// reduced from row `permutation[i]` of `self`.
+    pub(crate) fn row_echelon_permuted(&self) -> (Self, Vec<usize>) {
+        let mut result = self.clone();
+        let mut permutation: Vec<usize> = (0..self.rows).collect();
+        row_echelon(&mut result.data, self.cols, &mut permutation);
+        (result, permutation)
+    }
+
+    pub fn rank(&self) -> usize {
+        let rref = self.row_echelon();
+        (0..rref.rows)
+            .filter(|&i| rref[i].iter().any(|&x| x != K::zero()))
+            .count()
+    }
+
+    /// Row echelon form by partial pivoting, through the same elimination as
+    /// `LU`.
+    pub fn gaussian_elimination(&mut self) {
+        let mut permutation: Vec<usize> = (0..self.rows).collect();
+        lu::factorize(&mut self.data, self.cols, &mut permutation);
+        for i in 0..self.rows {
+            self[i].iter_mut().take(i).for_each(|x| *x = K::zero());
+        }
+    }
+
+    /// Through the same `P·A = L·U` factorization as `Matrix::inverse`.
+    pub fn inverse(&self) -> std::result::Result<DMatrix<K>, &'static str> {
+        if self.rows != self.cols {
+            return Err("Matrix is not square");
+        }
+
+        let n = self.rows;
+        let mut factors = self.data.clone();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        lu::factorize(&mut factors, n, &mut permutation);
+        lu::check_pivots(&factors, n)?;
+
+        let mut result = Self::zeros(n, n);
+        for col in 0..n {
+            let mut column: Vec<K> = permutation
+                .iter()
+                .map(|&row| if row == col { K::one() } else { K::zero() })
+                .collect();
+            lu::substitute(&factors, n, &mut column);
+            for (row, value) in column.into_iter().enumerate() {
+                result[row][col] = value;
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> From<Matrix<K, M, N>> for DMatrix<K> {
+    fn from(matrix: Matrix<K, M, N>) -> Self {
+        DMatrix {
+            data: matrix.iter().flatten().copied().collect(),
+            rows: M,
+            cols: N,
+        }
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> TryFrom<DMatrix<K>> for Matrix<K, M, N> {
+    type Error = &'static str;
+
+    fn try_from(matrix: DMatrix<K>) -> std::result::Result<Self, Self::Error> {
+        if (matrix.rows, matrix.cols) != (M, N) {
+            return Err("Matrix dimensions do not match");
+        }
+
+        let mut data = [[K::zero(); N]; M];
+        for (i, row) in data.iter_mut().enumerate() {
+            row.copy_from_slice(&matrix[i]);
+        }
+
+        Ok(Matrix::new(data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use rand::prelude::*;
+
+    const NB_TESTCASE_MATRICES: usize = 100;
+    const THRESHOLD: f64 = 1e-8;
+
+    fn generate_random_matrix(rows: usize, cols: usize) -> DMatrix<f64> {
+        let mut rng = rand::thread_rng();
+        DMatrix::new(rows, cols, (0..rows * cols).map(|_| rng.gen()).collect())
+    }
+
+    fn to_nalgebra(matrix: &DMatrix<f64>) -> nalgebra::DMatrix<f64> {
+        nalgebra::DMatrix::from_row_slice(matrix.rows(), matrix.cols(), matrix.as_slice())
+    }
+
+    fn assert_matrix_eq(matrix: &DMatrix<f64>, expected: &nalgebra::DMatrix<f64>) {
+        assert_eq!((matrix.rows(), matrix.cols()), expected.shape());
+        for i in 0..matrix.rows() {
+            for j in 0..matrix.cols() {
+                assert_abs_diff_eq!(matrix[i][j], expected[(i, j)], epsilon = THRESHOLD);
+            }
+        }
+    }
+
+    #[test]
+    fn dmatrix_mul() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let (m, n, p) = (
+                rng.gen_range(1..10),
+                rng.gen_range(1..10),
+                rng.gen_range(1..10),
+            );
+            let a = generate_random_matrix(m, n);
+            let b = generate_random_matrix(n, p);
+            let v = DVector::new((0..n).map(|_| rng.gen()).collect());
+
+            assert_matrix_eq(&a.mul_mat(&b), &(to_nalgebra(&a) * to_nalgebra(&b)));
+            assert_matrix_eq(&a.transpose(), &to_nalgebra(&a).transpose());
+
+            let expected = to_nalgebra(&a) * nalgebra::DVector::from_column_slice(&v);
+            for (i, &value) in a.mul_vec(&v).iter().enumerate() {
+                assert_abs_diff_eq!(value, expected[i], epsilon = THRESHOLD);
+            }
+        }
+    }
+
+    #[test]
+    fn dmatrix_determinant_inverse() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let n = rng.gen_range(1..10);
+            let a = generate_random_matrix(n, n);
+            let expected = to_nalgebra(&a);
+
+            assert_abs_diff_eq!(a.determinant(), expected.determinant(), epsilon = THRESHOLD);
+            assert_matrix_eq(&a.inverse().unwrap(), &expected.try_inverse().unwrap());
+        }
+
+        // Same factorization as the fixed-size inverse, so the same bits.
+        let matrix = Matrix::from([[8., 5., -2.], [4., 7., 20.], [7., 6., 1.]]);
+        assert_eq!(
+            DMatrix::from(matrix).inverse(),
+            Ok(DMatrix::from(matrix.inverse().unwrap()))
+        );
+    }
+
+    #[test]
+    fn dmatrix_rank_singular() {
+        let a = DMatrix::from_rows(&[
+            vec![1., 2., 0., 0.],
+            vec![2., 4., 0., 0.],
+            vec![-1., 2., 1., 1.],
+        ]);
+        assert_eq!(a.rank(), 2);
+        assert_eq!(
+            DMatrix::from_rows(&[vec![2., 4.], vec![1., 2.]]).inverse(),
+            Err("Matrix is singular")
+        );
+    }
+
+    #[test]
+    fn dmatrix_conversions() {
+        let matrix = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+        let dynamic = DMatrix::from(matrix);
+        assert_eq!((dynamic.rows(), dynamic.cols()), (2, 3));
+        assert_eq!(Matrix::<f64, 2, 3>::try_from(dynamic.clone()), Ok(matrix));
+        assert!(Matrix::<f64, 3, 2>::try_from(dynamic).is_err());
+
+        let vector = crate::vector::Vector::from([1., 2., 3.]);
+        let dynamic = DVector::from(vector);
+        assert_eq!(
+            crate::vector::Vector::<f64, 3>::try_from(dynamic.clone()),
+            Ok(vector)
+        );
+        assert!(crate::vector::Vector::<f64, 2>::try_from(dynamic).is_err());
+    }
+}
@@ -0,0 +1,461 @@
+use crate::dvector::DVector;
+use crate::field::{Field, Real, Ring};
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+
+use num_traits::{One, Zero};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter, Result};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Dual number `re + eps·ε` with `ε² = 0`. Evaluating a function on
+/// `Dual::variable(x)` carries its derivative at `x` in `eps`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dual<T> {
+    pub re: T,
+    pub eps: T,
+}
+
+impl<T: Real> Dual<T> {
+    pub fn new(re: T, eps: T) -> Self {
+        Dual { re, eps }
+    }
+
+    pub fn constant(re: T) -> Self {
+        Dual::new(re, T::zero())
+    }
+
+    pub fn variable(re: T) -> Self {
+        Dual::new(re, T::one())
+    }
+}
+
+impl<T: Display> Display for Dual<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*} + {:.*}ε", precision, self.re, precision, self.eps),
+            None => write!(f, "{} + {}ε", self.re, self.eps),
+        }
+    }
+}
+
+/// Orders by the real part alone, as the branches of `abs` and pivoting
+/// expect; unlike `==`, which also compares `eps`, two duals with the same
+/// real part are neither less nor greater than each other.
+impl<T: Real> PartialOrd for Dual<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.re.partial_cmp(&other.re)
+    }
+}
+
+impl<T: Real> Add for Dual<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Dual::new(self.re + rhs.re, self.eps + rhs.eps)
+    }
+}
+
+impl<T: Real> AddAssign for Dual<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Real> Sub for Dual<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Dual::new(self.re - rhs.re, self.eps - rhs.eps)
+    }
+}
+
+impl<T: Real> SubAssign for Dual<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Real> Mul for Dual<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Dual::new(self.re * rhs.re, self.re * rhs.eps + self.eps * rhs.re)
+    }
+}
+
+impl<T: Real> MulAssign for Dual<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Real> Div for Dual<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Dual::new(
+            self.re / rhs.re,
+            (self.eps * rhs.re - self.re * rhs.eps) / (rhs.re * rhs.re),
+        )
+    }
+}
+
+impl<T: Real> DivAssign for Dual<T> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: Real> Neg for Dual<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Dual::new(-self.re, -self.eps)
+    }
+}
+
+impl<T: Real> Zero for Dual<T> {
+    fn zero() -> Self {
+        Dual::constant(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.eps.is_zero()
+    }
+}
+
+impl<T: Real> One for Dual<T> {
+    fn one() -> Self {
+        Dual::constant(T::one())
+    }
+}
+
+impl<T: Real> Ring for Dual<T> {
+    /// By `|re|`, then by `|eps|`, so a pure `ε` still beats an exact zero.
+    fn is_better_pivot(self, current: Self) -> bool {
+        (self.re.abs(), self.eps.abs()) > (current.re.abs(), current.eps.abs())
+    }
+
+    /// Division only looks at `re`, so a pure `ε` cannot be divided by.
+    fn may_be_zero(self) -> bool {
+        self.re.is_zero()
+    }
+}
+
+impl<T: Real> Field for Dual<T> {}
+
+impl<T: Real> Real for Dual<T> {
+    fn from_f64(value: f64) -> Self {
+        Dual::constant(T::from_f64(value))
+    }
+
+    fn abs(self) -> Self {
+        if self.re < T::zero() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    fn sqrt(self) -> Self {
+        let root = self.re.sqrt();
+        Dual::new(root, self.eps / (root + root))
+    }
+
+    fn sin(self) -> Self {
+        Dual::new(self.re.sin(), self.eps * self.re.cos())
+    }
+
+    fn cos(self) -> Self {
+        Dual::new(self.re.cos(), -self.eps * self.re.sin())
+    }
+
+    fn tan(self) -> Self {
+        let tan = self.re.tan();
+        Dual::new(tan, self.eps * (T::one() + tan * tan))
+    }
+
+    fn exp(self) -> Self {
+        let exp = self.re.exp();
+        Dual::new(exp, self.eps * exp)
+    }
+
+    fn ln(self) -> Self {
+        Dual::new(self.re.ln(), self.eps / self.re)
+    }
+}
+
+// Dual is not a `RealField`: its `f64` norms would drop `eps`, so the norms
+// stay dual-valued and carry their derivative.
+impl<T: Real, const N: usize> Vector<Dual<T>, N> {
+    pub fn norm_1(&self) -> Dual<T> {
+        self.iter().fold(Dual::zero(), |acc, &x| acc + x.abs())
+    }
+
+    pub fn norm(&self) -> Dual<T> {
+        self.dot(self).sqrt()
+    }
+
+    pub fn norm_inf(&self) -> Dual<T> {
+        self.iter()
+            .map(|x| x.abs())
+            .fold(Dual::zero(), |max, x| if x > max { x } else { max })
+    }
+}
+
+impl<T: Real> DVector<Dual<T>> {
+    pub fn norm_1(&self) -> Dual<T> {
+        self.iter().fold(Dual::zero(), |acc, &x| acc + x.abs())
+    }
+
+    pub fn norm(&self) -> Dual<T> {
+        self.dot(self).sqrt()
+    }
+
+    pub fn norm_inf(&self) -> Dual<T> {
+        self.iter()
+            .map(|x| x.abs())
+            .fold(Dual::zero(), |max, x| if x > max { x } else { max })
+    }
+}
+
+pub fn derivative<T: Real, F: Fn(Dual<T>) -> Dual<T>>(f: F, x: T) -> T {
+    f(Dual::variable(x)).eps
+}
+
+/// One forward pass per input, seeding `eps = 1` on one coordinate at a time.
+pub fn gradient<T, F, const N: usize>(f: F, x: &Vector<T, N>) -> Vector<T, N>
+where
+    T: Real,
+    F: Fn(&Vector<Dual<T>, N>) -> Dual<T>,
+{
+    let mut result = [T::zero(); N];
+    for (j, partial) in result.iter_mut().enumerate() {
+        *partial = f(&seed(x, j)).eps;
+    }
+
+    Vector::new(result)
+}
+
+pub fn jacobian<T, F, const M: usize, const N: usize>(f: F, x: &Vector<T, N>) -> Matrix<T, M, N>
+where
+    T: Real,
+    F: Fn(&Vector<Dual<T>, N>) -> Vector<Dual<T>, M>,
+{
+    let mut result = [[T::zero(); N]; M];
+    for j in 0..N {
+        for (row, output) in result.iter_mut().zip(f(&seed(x, j))) {
+            row[j] = output.eps;
+        }
+    }
+
+    Matrix::new(result)
+}
+
+fn seed<T: Real, const N: usize>(x: &Vector<T, N>, index: usize) -> Vector<Dual<T>, N> {
+    let mut data = [Dual::zero(); N];
+    for (j, (dual, &value)) in data.iter_mut().zip(x.iter()).enumerate() {
+        *dual = if j == index {
+            Dual::variable(value)
+        } else {
+            Dual::constant(value)
+        };
+    }
+
+    Vector::new(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::projection;
+    use approx::assert_abs_diff_eq;
+    use rand::prelude::*;
+
+    const NB_TESTCASE_MATRICES: usize = 100;
+    const THRESHOLD: f64 = 1e-8;
+
+    fn generate_random_matrix<const N: usize>() -> Matrix<f64, N, N> {
+        let mut rng = rand::thread_rng();
+        let mut data = [[0.0; N]; N];
+        for row in &mut data {
+            for elem in row {
+                *elem = rng.gen_range(-1.0..1.0);
+            }
+        }
+        Matrix::new(data)
+    }
+
+    fn along<const N: usize>(
+        a: &Matrix<f64, N, N>,
+        da: &Matrix<f64, N, N>,
+    ) -> Matrix<Dual<f64>, N, N> {
+        let mut data = [[Dual::zero(); N]; N];
+        for (i, row) in data.iter_mut().enumerate() {
+            for (j, elem) in row.iter_mut().enumerate() {
+                *elem = Dual::new(a[i][j], da[i][j]);
+            }
+        }
+        Matrix::new(data)
+    }
+
+    #[test]
+    fn dual_elementary() {
+        assert_abs_diff_eq!(derivative(|x| x * x * x, 2.0), 12.0, epsilon = THRESHOLD);
+        assert_abs_diff_eq!(
+            derivative(|x| Dual::one() / x, 4.0),
+            -1.0 / 16.0,
+            epsilon = THRESHOLD
+        );
+        assert_abs_diff_eq!(
+            derivative(|x| x.sin() * x.exp(), 0.0),
+            1.0,
+            epsilon = THRESHOLD
+        );
+        assert_abs_diff_eq!(
+            derivative(|x| x.sqrt().ln(), 3.0),
+            1.0 / 6.0,
+            epsilon = THRESHOLD
+        );
+        assert_eq!(format!("{:.3}", Dual::new(1.0, 0.5)), "1.000 + 0.500ε");
+    }
+
+    #[test]
+    fn dual_norms() {
+        // At t = 1: ‖(3t, 4)‖ = 5 with derivative 9/5, ‖(t, -2t)‖₁ = 3 with
+        // derivative 3, and ‖(t, 3t²)‖∞ = 3 with derivative 6.
+        let t = Dual::variable(1.0);
+        let norm = Vector::new([t * Dual::from_f64(3.0), Dual::from_f64(4.0)]).norm();
+        assert_abs_diff_eq!(norm.re, 5.0, epsilon = THRESHOLD);
+        assert_abs_diff_eq!(norm.eps, 1.8, epsilon = THRESHOLD);
+
+        let v = DVector::new(vec![t, -(t + t)]);
+        assert_eq!(v.norm_1(), Dual::new(3.0, 3.0));
+        assert_abs_diff_eq!(v.norm().eps, 5.0_f64.sqrt(), epsilon = THRESHOLD);
+
+        let w = Vector::new([t, Dual::from_f64(3.0) * t * t]);
+        assert_eq!(w.norm_inf(), Dual::new(3.0, 6.0));
+    }
+
+    #[test]
+    fn dual_determinant() {
+        // Jacobi's formula: d det(A) = det(A) tr(A⁻¹ dA).
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let a = generate_random_matrix::<4>();
+            let da = generate_random_matrix::<4>();
+            let Ok(inverse) = a.inverse() else { continue };
+
+            let det = along(&a, &da).determinant();
+            assert_abs_diff_eq!(det.re, a.determinant(), epsilon = THRESHOLD);
+            assert_abs_diff_eq!(
+                det.eps,
+                a.determinant() * inverse.mul_mat(&da).trace(),
+                epsilon = 1e-6
+            );
+        }
+    }
+
+    #[test]
+    fn dual_singular_point() {
+        // det(diag(t, 1, 1)) = t, differentiated at t = 0.
+        let t = Dual::variable(0.0);
+        let (zero, one) = (Dual::zero(), Dual::one());
+        let a = Matrix::new([[t, zero, zero], [zero, one, zero], [zero, zero, one]]);
+        assert_eq!(a.determinant(), t);
+        assert_eq!(a.lu().determinant(), t);
+        assert_eq!(crate::DMatrix::from(a).determinant(), t);
+        assert_eq!(a.inverse(), Err("Matrix is possibly singular"));
+
+        // The exact zero ties with t on the real part, but pivoting on it
+        // would drop the derivative of det = -t.
+        let a = Matrix::new([[zero, one], [t, zero]]);
+        assert_eq!(a.determinant(), -t);
+        assert_eq!(a.lu().determinant(), -t);
+        assert_eq!(crate::DMatrix::from(a).determinant(), -t);
+
+        // Elimination meets a pivot column whose real parts all vanish.
+        let a = Matrix::new([[1.0, 2.0, 0.0], [2.0, 4.0, 0.0], [0.0, 0.0, 1.0]]);
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let da = generate_random_matrix::<3>();
+            let h = 1e-6;
+            let (mut plus, mut minus) = (a, a);
+            for i in 0..3 {
+                for j in 0..3 {
+                    plus[i][j] += h * da[i][j];
+                    minus[i][j] -= h * da[i][j];
+                }
+            }
+            let expected = (plus.determinant() - minus.determinant()) / (2.0 * h);
+
+            let det = along(&a, &da).determinant();
+            assert_abs_diff_eq!(det.re, 0.0, epsilon = THRESHOLD);
+            assert_abs_diff_eq!(det.eps, expected, epsilon = 1e-6);
+        }
+
+        assert!(Dual::new(1.0, 2.0) <= Dual::new(1.0, 3.0));
+        assert!(Dual::new(1.0, 2.0) >= Dual::new(1.0, 3.0));
+        assert_ne!(Dual::new(1.0, 2.0), Dual::new(1.0, 3.0));
+    }
+
+    #[test]
+    fn dual_inverse() {
+        // d(A⁻¹) = -A⁻¹ dA A⁻¹.
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let a = generate_random_matrix::<3>();
+            let da = generate_random_matrix::<3>();
+            let Ok(inverse) = a.inverse() else { continue };
+            if a.determinant().abs() < 1e-3 {
+                continue;
+            }
+
+            let dual_inverse = along(&a, &da).inverse().unwrap();
+            let expected = inverse.mul_mat(&da).mul_mat(&inverse);
+            for i in 0..3 {
+                for j in 0..3 {
+                    assert_abs_diff_eq!(dual_inverse[i][j].re, inverse[i][j], epsilon = 1e-6);
+                    assert_abs_diff_eq!(dual_inverse[i][j].eps, -expected[i][j], epsilon = 1e-6);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn dual_projection_fov() {
+        let (fov, ratio, near, far): (f64, f64, f64, f64) = (1.2, 16.0 / 9.0, 0.1, 100.0);
+        let c = |value| Dual::constant(value);
+        let d = projection(Dual::variable(fov), c(ratio), c(near), c(far));
+
+        // d/dθ cot(θ/2) = -1 / (2 sin²(θ/2)).
+        let dscale = -1.0 / (2.0 * (fov / 2.0).sin().powi(2));
+        assert_abs_diff_eq!(d[0][0].eps, dscale / ratio, epsilon = THRESHOLD);
+        assert_abs_diff_eq!(d[1][1].eps, dscale, epsilon = THRESHOLD);
+        assert_abs_diff_eq!(d[2][2].eps, 0.0, epsilon = THRESHOLD);
+        assert_abs_diff_eq!(
+            d[1][1].re,
+            projection(fov, ratio, near, far)[1][1],
+            epsilon = THRESHOLD
+        );
+    }
+
+    #[test]
+    fn dual_gradient_jacobian() {
+        let x = Vector::new([1.0, -2.0, 0.5]);
+        let grad = gradient(|v| v.dot(v), &x);
+        assert_eq!(grad, Vector::new([2.0, -4.0, 1.0]));
+
+        let a = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let lifted = Matrix::new(a.map(|row| row.map(Dual::constant)));
+        let jac = jacobian(|v| lifted.mul_vec(v), &x);
+        assert_eq!(jac, a);
+
+        let polar = jacobian(
+            |v: &Vector<Dual<f64>, 2>| Vector::new([v[0] * v[1].cos(), v[0] * v[1].sin()]),
+            &Vector::new([2.0, 0.3]),
+        );
+        assert_abs_diff_eq!(polar[0][0], 0.3f64.cos(), epsilon = THRESHOLD);
+        assert_abs_diff_eq!(polar[0][1], -2.0 * 0.3f64.sin(), epsilon = THRESHOLD);
+        assert_abs_diff_eq!(polar[1][0], 0.3f64.sin(), epsilon = THRESHOLD);
+        assert_abs_diff_eq!(polar[1][1], 2.0 * 0.3f64.cos(), epsilon = THRESHOLD);
+    }
+}
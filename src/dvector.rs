@@ -0,0 +1,282 @@
+use crate::field::{Field, RealField, Ring};
+use crate::vector::Vector;
+use crate::view::VectorAccess;
+
+use std::fmt::{Display, Formatter, Result};
+use std::ops::{
+    Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DVector<K> {
+    data: Vec<K>,
+}
+
+impl<K: Ring + Display> Display for DVector<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "[")?;
+        for (index, item) in self.iter().enumerate() {
+            if index != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{:.3}", item)?;
+        }
+        writeln!(f, "]")?;
+        Ok(())
+    }
+}
+
+impl<K: Ring> Deref for DVector<K> {
+    type Target = [K];
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<K: Ring> DerefMut for DVector<K> {
+    fn deref_mut(&mut self) -> &mut [K] {
+        &mut self.data
+    }
+}
+
+impl<K> IntoIterator for DVector<K> {
+    type Item = K;
+    type IntoIter = std::vec::IntoIter<K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<K: Ring> Add<DVector<K>> for DVector<K> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut data = self;
+        data.operate(&rhs, |a, b| a + b);
+
+        data
+    }
+}
+
+impl<K: Ring> Add<K> for DVector<K> {
+    type Output = Self;
+
+    fn add(self, scalar: K) -> Self::Output {
+        let mut data = self;
+        data.operate_scalar(scalar, |a, b| a + b);
+
+        data
+    }
+}
+
+impl<K: Ring> AddAssign<DVector<K>> for DVector<K> {
+    fn add_assign(&mut self, rhs: DVector<K>) {
+        self.operate(&rhs, |a, b| a + b);
+    }
+}
+
+impl<K: Ring> AddAssign<K> for DVector<K> {
+    fn add_assign(&mut self, scalar: K) {
+        self.operate_scalar(scalar, |a, b| a + b);
+    }
+}
+
+impl<K: Ring> Sub<DVector<K>> for DVector<K> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut data = self;
+        data.operate(&rhs, |a, b| a - b);
+
+        data
+    }
+}
+
+impl<K: Ring> Sub<K> for DVector<K> {
+    type Output = Self;
+
+    fn sub(self, scalar: K) -> Self::Output {
+        let mut data = self;
+        data.operate_scalar(scalar, |a, b| a - b);
+
+        data
+    }
+}
+
+impl<K: Ring> SubAssign<DVector<K>> for DVector<K> {
+    fn sub_assign(&mut self, rhs: DVector<K>) {
+        self.operate(&rhs, |a, b| a - b);
+    }
+}
+
+impl<K: Ring> SubAssign<K> for DVector<K> {
+    fn sub_assign(&mut self, scalar: K) {
+        self.operate_scalar(scalar, |a, b| a - b);
+    }
+}
+
+impl<K: Ring> Mul<DVector<K>> for DVector<K> {
+    type Output = K;
+
+    fn mul(self, rhs: DVector<K>) -> K {
+        self.dot(&rhs)
+    }
+}
+
+impl<K: Ring> Mul<K> for DVector<K> {
+    type Output = Self;
+
+    fn mul(self, scalar: K) -> Self::Output {
+        let mut data = self;
+        data.operate_scalar(scalar, |a, b| a * b);
+
+        data
+    }
+}
+
+impl<K: Ring> MulAssign<K> for DVector<K> {
+    fn mul_assign(&mut self, scalar: K) {
+        self.operate_scalar(scalar, |a, b| a * b);
+    }
+}
+
+impl<K: Field> Div<K> for DVector<K> {
+    type Output = Self;
+
+    fn div(self, scalar: K) -> Self::Output {
+        if scalar == K::zero() {
+            panic!("Division by zero error")
+        }
+
+        let mut data = self;
+        data.operate_scalar(scalar, |a, b| a / b);
+
+        data
+    }
+}
+
+impl<K: Field> DivAssign<K> for DVector<K> {
+    fn div_assign(&mut self, scalar: K) {
+        self.operate_scalar(scalar, |a, b| a / b);
+    }
+}
+
+impl<K: Ring> Neg for DVector<K> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        DVector {
+            data: self.data.into_iter().map(|x| -x).collect(),
+        }
+    }
+}
+
+impl<K: Ring> DVector<K> {
+    fn operate<F: Fn(K, K) -> K>(&mut self, v: &DVector<K>, op: F) {
+        assert_eq!(self.len(), v.len(), "Vector dimensions must match");
+        self.iter_mut()
+            .zip(&v.data)
+            .for_each(|(a, b)| *a = op(*a, *b));
+    }
+
+    fn operate_scalar<F: Fn(K, K) -> K>(&mut self, scalar: K, op: F) {
+        self.iter_mut().for_each(|a| *a = op(*a, scalar));
+    }
+
+    pub fn new(data: Vec<K>) -> Self {
+        DVector { data }
+    }
+
+    pub fn zeros(dim: usize) -> Self {
+        DVector {
+            data: vec![K::zero(); dim],
+        }
+    }
+
+    pub fn dim(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn add(&mut self, v: &DVector<K>) {
+        self.operate(v, |a, b| a + b);
+    }
+
+    pub fn sub(&mut self, v: &DVector<K>) {
+        self.operate(v, |a, b| a - b);
+    }
+
+    pub fn scl(&mut self, scalar: K) {
+        self.operate_scalar(scalar, |a, b| a * b);
+    }
+
+    pub fn dot<V: VectorAccess<K> + ?Sized>(&self, v: &V) -> K {
+        assert_eq!(self.len(), v.dim(), "Vector dimensions must match");
+        self.iter()
+            .enumerate()
+            .fold(K::zero(), |acc, (i, &x)| acc + x.conjugate() * v.at(i))
+    }
+
+    pub fn linear_combination(u: &[DVector<K>], coefs: &[K]) -> DVector<K> {
+        assert_eq!(
+            u.len(),
+            coefs.len(),
+            "Number of vectors and coefficients must match"
+        );
+
+        let mut result = DVector::zeros(u.first().map_or(0, |v| v.dim()));
+        for (vector, &coef) in u.iter().zip(coefs.iter()) {
+            result.operate(vector, |a, b| a + coef * b);
+        }
+
+        result
+    }
+}
+
+impl<K: Field> DVector<K> {
+    pub fn inv_scl(&mut self, scalar: K) {
+        self.operate_scalar(scalar, |a, b| a / b);
+    }
+}
+
+impl<K: RealField> DVector<K> {
+    pub fn norm_1(&self) -> f64 {
+        self.iter()
+            .fold(f64::default(), |acc, &x| acc + x.modulus())
+    }
+
+    pub fn norm(&self) -> f64 {
+        self.dot(self).modulus().sqrt()
+    }
+
+    pub fn norm_inf(&self) -> f64 {
+        self.iter()
+            .fold(f64::NEG_INFINITY, |max, &x| x.modulus().max(max))
+    }
+}
+
+impl<K: Ring> From<Vec<K>> for DVector<K> {
+    fn from(data: Vec<K>) -> Self {
+        DVector::new(data)
+    }
+}
+
+impl<K: Ring, const N: usize> From<Vector<K, N>> for DVector<K> {
+    fn from(vector: Vector<K, N>) -> Self {
+        DVector::new(vector.to_vec())
+    }
+}
+
+impl<K: Ring, const N: usize> TryFrom<DVector<K>> for Vector<K, N> {
+    type Error = &'static str;
+
+    fn try_from(vector: DVector<K>) -> std::result::Result<Self, Self::Error> {
+        let data: [K; N] = vector
+            .data
+            .try_into()
+            .map_err(|_| "Vector dimension does not match")?;
+
+        Ok(Vector::new(data))
+    }
+}
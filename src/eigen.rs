@@ -0,0 +1,754 @@
+use crate::field::{Field, Real};
+use crate::matrix::Matrix;
+
+use num_complex::Complex;
+use num_traits::{One, Zero};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+const MAX_SWEEPS: usize = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EigenError {
+    /// The iteration was still running after this many steps.
+    NoConvergence(usize),
+}
+
+impl Display for EigenError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            EigenError::NoConvergence(steps) => {
+                write!(f, "Eigenvalue iteration did not converge in {steps} steps")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EigenError {}
+
+/// `A = V·Λ·Vᵀ` for a symmetric `A`, with the eigenvalues in increasing
+/// order and the matching orthonormal eigenvectors as the columns of `V`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SymmetricEigen<K, const N: usize> {
+    eigenvalues: [K; N],
+    eigenvectors: Matrix<K, N, N>,
+}
+
+impl<K: Real, const N: usize> SymmetricEigen<K, N> {
+    /// Cyclic Jacobi: sweeps of plane rotations, each zeroing one
+    /// off-diagonal pair, until the off-diagonal part is negligible. Only the
+    /// lower triangle of `matrix` is read.
+    pub fn new(matrix: &Matrix<K, N, N>) -> Result<Self, EigenError> {
+        // Rotations leave rounding residue of order `ε·‖A‖` in each entry, so
+        // the off-diagonal mass cannot be pushed much below `(N·ε)²·‖A‖²`.
+        let epsilon = K::from_f64(N as f64 * f64::EPSILON);
+        let mut a: Matrix<K, N, N> = Matrix::new(std::array::from_fn(|i| {
+            std::array::from_fn(|j| if i >= j { matrix[i][j] } else { matrix[j][i] })
+        }));
+        let mut v = Matrix::<K, N, N>::identity();
+
+        let total = a.iter().flatten().fold(K::zero(), |acc, &x| acc + x * x);
+        let mut converged = false;
+        for _ in 0..MAX_SWEEPS {
+            let off = (0..N)
+                .flat_map(|i| (0..i).map(move |j| (i, j)))
+                .fold(K::zero(), |acc, (i, j)| acc + a[i][j] * a[i][j]);
+            if off <= epsilon * epsilon * total {
+                converged = true;
+                break;
+            }
+
+            for p in 0..N {
+                for q in (p + 1)..N {
+                    if a[p][q] == K::zero() {
+                        continue;
+                    }
+                    let theta = (a[q][q] - a[p][p]) / (a[p][q] + a[p][q]);
+                    let t = K::one() / (theta.abs() + (theta * theta + K::one()).sqrt());
+                    let t = if theta < K::zero() { -t } else { t };
+                    let c = K::one() / (t * t + K::one()).sqrt();
+                    let s = t * c;
+
+                    for row in a.iter_mut().chain(v.iter_mut()) {
+                        let (x, y) = (row[p], row[q]);
+                        row[p] = c * x - s * y;
+                        row[q] = s * x + c * y;
+                    }
+                    let (x, y) = (a[p], a[q]);
+                    for k in 0..N {
+                        a[p][k] = c * x[k] - s * y[k];
+                        a[q][k] = s * x[k] + c * y[k];
+                    }
+                }
+            }
+        }
+        if !converged {
+            return Err(EigenError::NoConvergence(MAX_SWEEPS));
+        }
+
+        let mut order: [usize; N] = std::array::from_fn(|i| i);
+        order.sort_by(|&i, &j| a[i][i].partial_cmp(&a[j][j]).unwrap_or(Ordering::Equal));
+        Ok(SymmetricEigen {
+            eigenvalues: order.map(|i| a[i][i]),
+            eigenvectors: Matrix::new(std::array::from_fn(|i| order.map(|j| v[i][j]))),
+        })
+    }
+
+    /// Eigenvalues in increasing order.
+    pub fn eigenvalues(&self) -> &[K; N] {
+        &self.eigenvalues
+    }
+
+    /// Column `j` is the unit eigenvector for `eigenvalues()[j]`.
+    pub fn eigenvectors(&self) -> &Matrix<K, N, N> {
+        &self.eigenvectors
+    }
+
+    /// `V·Λ·Vᵀ`.
+    pub fn recompose(&self) -> Matrix<K, N, N> {
+        let v = &self.eigenvectors;
+        Matrix::new(std::array::from_fn(|i| {
+            std::array::from_fn(|j| {
+                (0..N).fold(K::zero(), |acc, k| {
+                    acc + v[i][k] * self.eigenvalues[k] * v[j][k]
+                })
+            })
+        }))
+    }
+}
+
+impl<K: Real, const N: usize> Matrix<K, N, N> {
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<K, N>, EigenError> {
+        SymmetricEigen::new(self)
+    }
+
+    pub fn symmetric_eigenvalues(&self) -> Result<[K; N], EigenError> {
+        Ok(*SymmetricEigen::new(self)?.eigenvalues())
+    }
+}
+
+const MAX_ITERATIONS: usize = 100;
+
+/// Eigenvalues of a general real matrix with unit eigenvectors as the
+/// matching columns of `eigenvectors`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Eigen<K, const N: usize> {
+    eigenvalues: [Complex<K>; N],
+    eigenvectors: Matrix<Complex<K>, N, N>,
+}
+
+impl<K, const N: usize> Eigen<K, N> {
+    pub fn eigenvalues(&self) -> &[Complex<K>; N] {
+        &self.eigenvalues
+    }
+
+    pub fn eigenvectors(&self) -> &Matrix<Complex<K>, N, N> {
+        &self.eigenvectors
+    }
+}
+
+impl<K: Real, const N: usize> Matrix<K, N, N> {
+    /// `(Q, H)` with `A = Q·H·Qᵀ`, `Q` orthogonal and `H` upper Hessenberg
+    /// (zero below the first subdiagonal), using Householder reflections.
+    pub fn hessenberg(&self) -> (Matrix<K, N, N>, Matrix<K, N, N>) {
+        let mut h = *self;
+        let mut q = Matrix::<K, N, N>::identity();
+
+        for k in 0..N.saturating_sub(2) {
+            let tail = ((k + 2)..N).fold(K::zero(), |acc, i| acc + h[i][k] * h[i][k]);
+            if tail == K::zero() {
+                continue;
+            }
+            let head = h[k + 1][k];
+            let norm = (head * head + tail).sqrt();
+
+            let mut v = [K::zero(); N];
+            for i in (k + 1)..N {
+                v[i] = h[i][k];
+            }
+            v[k + 1] = if head < K::zero() {
+                head - norm
+            } else {
+                head + norm
+            };
+            let scale = (K::one() + K::one()) / v.iter().fold(K::zero(), |acc, &x| acc + x * x);
+
+            // `P = I - scale·v·vᵀ`, applied as `P·H·P` and `Q·P`.
+            for j in 0..N {
+                let s = scale * ((k + 1)..N).fold(K::zero(), |acc, i| acc + v[i] * h[i][j]);
+                for i in (k + 1)..N {
+                    h[i][j] -= s * v[i];
+                }
+            }
+            for row in h.iter_mut().chain(q.iter_mut()) {
+                let s = scale * ((k + 1)..N).fold(K::zero(), |acc, j| acc + row[j] * v[j]);
+                for j in (k + 1)..N {
+                    row[j] -= s * v[j];
+                }
+            }
+            for row in h.iter_mut().skip(k + 2) {
+                row[k] = K::zero();
+            }
+        }
+
+        (q, h)
+    }
+
+    /// Real Schur form `(Q, T)` with `A = Q·T·Qᵀ`: `T` is upper triangular
+    /// except for 2×2 diagonal blocks holding complex conjugate eigenvalue
+    /// pairs. Francis double-shift QR on the Hessenberg form, following
+    /// EISPACK's `hqr2`.
+    pub fn schur(&self) -> Result<(Matrix<K, N, N>, Matrix<K, N, N>), EigenError> {
+        let (mut v, mut h) = self.hessenberg();
+        if N == 0 {
+            return Ok((v, h));
+        }
+
+        let epsilon = K::from_f64(f64::EPSILON);
+        let two = K::one() + K::one();
+        let norm = (0..N).fold(K::zero(), |acc, i| {
+            (i.saturating_sub(1)..N).fold(acc, |acc, j| acc + h[i][j].abs())
+        });
+        let mut exshift = K::zero();
+        let mut iterations = 0;
+        let mut steps = 0;
+        let mut n = N - 1;
+
+        loop {
+            // Look for a single small subdiagonal element.
+            let mut l = n;
+            while l > 0 {
+                let mut s = h[l - 1][l - 1].abs() + h[l][l].abs();
+                if s == K::zero() {
+                    s = norm;
+                }
+                if h[l][l - 1].abs() <= epsilon * s {
+                    h[l][l - 1] = K::zero();
+                    break;
+                }
+                l -= 1;
+            }
+
+            if l == n {
+                // One root found.
+                h[n][n] += exshift;
+                iterations = 0;
+                if n == 0 {
+                    break;
+                }
+                n -= 1;
+            } else if l + 1 == n {
+                // Two roots found.
+                let w = h[n][n - 1] * h[n - 1][n];
+                let p = (h[n - 1][n - 1] - h[n][n]) / two;
+                let q = p * p + w;
+                h[n][n] += exshift;
+                h[n - 1][n - 1] += exshift;
+
+                if q >= K::zero() {
+                    // A real pair: rotate the block to upper triangular.
+                    let z = q.sqrt();
+                    let z = if p >= K::zero() { p + z } else { p - z };
+                    let x = h[n][n - 1];
+                    let s = x.abs() + z.abs();
+                    let (p, q) = (x / s, z / s);
+                    let r = (p * p + q * q).sqrt();
+                    let (p, q) = (p / r, q / r);
+
+                    for j in (n - 1)..N {
+                        let z = h[n - 1][j];
+                        h[n - 1][j] = q * z + p * h[n][j];
+                        h[n][j] = q * h[n][j] - p * z;
+                    }
+                    for row in h.iter_mut().take(n + 1).chain(v.iter_mut()) {
+                        let z = row[n - 1];
+                        row[n - 1] = q * z + p * row[n];
+                        row[n] = q * row[n] - p * z;
+                    }
+                    h[n][n - 1] = K::zero();
+                }
+
+                iterations = 0;
+                if n < 2 {
+                    break;
+                }
+                n -= 2;
+            } else {
+                if iterations == MAX_ITERATIONS {
+                    return Err(EigenError::NoConvergence(steps));
+                }
+
+                // Form the shift.
+                let mut x = h[n][n];
+                let mut y = h[n - 1][n - 1];
+                let mut w = h[n][n - 1] * h[n - 1][n];
+
+                // Exceptional shifts break cycles of the standard one.
+                if iterations == 10 {
+                    exshift += x;
+                    for i in 0..=n {
+                        h[i][i] -= x;
+                    }
+                    let s = h[n][n - 1].abs() + h[n - 1][n - 2].abs();
+                    x = K::from_f64(0.75) * s;
+                    y = x;
+                    w = K::from_f64(-0.4375) * s * s;
+                }
+                if iterations == 30 {
+                    let s = (y - x) / two;
+                    let s = s * s + w;
+                    if s > K::zero() {
+                        let s = s.sqrt();
+                        let s = if y < x { -s } else { s };
+                        let s = x - w / ((y - x) / two + s);
+                        for i in 0..=n {
+                            h[i][i] -= s;
+                        }
+                        exshift += s;
+                        x = K::from_f64(0.964);
+                        y = x;
+                        w = x;
+                    }
+                }
+                iterations += 1;
+                steps += 1;
+
+                // Look for two consecutive small subdiagonal elements.
+                let mut m = n - 2;
+                let (mut p, mut q, mut r);
+                loop {
+                    let z = h[m][m];
+                    let (rr, ss) = (x - z, y - z);
+                    p = (rr * ss - w) / h[m + 1][m] + h[m][m + 1];
+                    q = h[m + 1][m + 1] - z - rr - ss;
+                    r = h[m + 2][m + 1];
+                    let s = p.abs() + q.abs() + r.abs();
+                    p /= s;
+                    q /= s;
+                    r /= s;
+                    if m == l {
+                        break;
+                    }
+                    let lhs = h[m][m - 1].abs() * (q.abs() + r.abs());
+                    let rhs = epsilon
+                        * p.abs()
+                        * (h[m - 1][m - 1].abs() + z.abs() + h[m + 1][m + 1].abs());
+                    if lhs < rhs {
+                        break;
+                    }
+                    m -= 1;
+                }
+
+                for i in (m + 2)..=n {
+                    h[i][i - 2] = K::zero();
+                    if i > m + 2 {
+                        h[i][i - 3] = K::zero();
+                    }
+                }
+
+                // Double QR step on rows `l..=n` and columns `m..=n`.
+                for k in m..n {
+                    let notlast = k + 1 != n;
+                    let mut x = K::zero();
+                    if k != m {
+                        p = h[k][k - 1];
+                        q = h[k + 1][k - 1];
+                        r = if notlast { h[k + 2][k - 1] } else { K::zero() };
+                        x = p.abs() + q.abs() + r.abs();
+                        if x == K::zero() {
+                            continue;
+                        }
+                        p /= x;
+                        q /= x;
+                        r /= x;
+                    }
+                    let s = (p * p + q * q + r * r).sqrt();
+                    let s = if p < K::zero() { -s } else { s };
+                    if s == K::zero() {
+                        continue;
+                    }
+                    if k != m {
+                        h[k][k - 1] = -s * x;
+                    } else if l != m {
+                        h[k][k - 1] = -h[k][k - 1];
+                    }
+                    p += s;
+                    let (x, y, z) = (p / s, q / s, r / s);
+                    q /= p;
+                    r /= p;
+
+                    for j in k..N {
+                        let mut p = h[k][j] + q * h[k + 1][j];
+                        if notlast {
+                            p += r * h[k + 2][j];
+                            h[k + 2][j] -= p * z;
+                        }
+                        h[k][j] -= p * x;
+                        h[k + 1][j] -= p * y;
+                    }
+                    let rows = (n.min(k + 3) + 1).min(N);
+                    for row in h.iter_mut().take(rows).chain(v.iter_mut()) {
+                        let mut p = x * row[k] + y * row[k + 1];
+                        if notlast {
+                            p += z * row[k + 2];
+                            row[k + 2] -= p * r;
+                        }
+                        row[k] -= p;
+                        row[k + 1] -= p * q;
+                    }
+                }
+            }
+        }
+
+        for i in 0..N {
+            for j in 0..i.saturating_sub(1) {
+                h[i][j] = K::zero();
+            }
+        }
+        Ok((v, h))
+    }
+
+    /// All eigenvalues, complex conjugate pairs included, sorted by real part
+    /// then imaginary part.
+    pub fn eigenvalues(&self) -> Result<[Complex<K>; N], EigenError> {
+        let (_, t) = self.schur()?;
+        let mut eigenvalues = schur_eigenvalues(&t).map(|(lambda, _, _)| lambda);
+        eigenvalues.sort_by(compare_complex);
+        Ok(eigenvalues)
+    }
+}
+
+impl<K: Real, const N: usize> Matrix<K, N, N>
+where
+    Complex<K>: Field,
+{
+    /// Eigenvalues as in `eigenvalues` with their eigenvectors, from back
+    /// substitution on the Schur form.
+    pub fn eigen(&self) -> Result<Eigen<K, N>, EigenError> {
+        let (q, t) = self.schur()?;
+        let tiny = K::from_f64(f64::EPSILON)
+            * t.iter().flatten().fold(
+                K::zero(),
+                |acc, &x| if x.abs() > acc { x.abs() } else { acc },
+            );
+        let tiny = if tiny == K::zero() { K::one() } else { tiny };
+
+        let mut pairs = schur_eigenvalues(&t).map(|(lambda, start, size)| {
+            let y = schur_eigenvector(&t, lambda, start, size, tiny);
+            let mut x: [Complex<K>; N] = std::array::from_fn(|i| {
+                (0..N).fold(Complex::zero(), |acc, j| acc + real(q[i][j]) * y[j])
+            });
+            let norm = x
+                .iter()
+                .fold(K::zero(), |acc, z| acc + z.re * z.re + z.im * z.im)
+                .sqrt();
+            for z in x.iter_mut() {
+                *z /= real(norm);
+            }
+            (lambda, x)
+        });
+        pairs.sort_by(|a, b| compare_complex(&a.0, &b.0));
+
+        Ok(Eigen {
+            eigenvalues: pairs.map(|(lambda, _)| lambda),
+            eigenvectors: Matrix::new(std::array::from_fn(|i| pairs.map(|(_, x)| x[i]))),
+        })
+    }
+}
+
+fn real<K: Real>(x: K) -> Complex<K> {
+    Complex::new(x, K::zero())
+}
+
+fn compare_complex<K: Real>(a: &Complex<K>, b: &Complex<K>) -> Ordering {
+    a.re.partial_cmp(&b.re)
+        .unwrap_or(Ordering::Equal)
+        .then(a.im.partial_cmp(&b.im).unwrap_or(Ordering::Equal))
+}
+
+/// Eigenvalues of a real Schur form with the start and size of the
+/// diagonal block each one comes from.
+fn schur_eigenvalues<K: Real, const N: usize>(
+    t: &Matrix<K, N, N>,
+) -> [(Complex<K>, usize, usize); N] {
+    let two = K::one() + K::one();
+    let mut result = [(real(K::zero()), 0, 1); N];
+    let mut i = 0;
+    while i < N {
+        if i + 1 < N && t[i + 1][i] != K::zero() {
+            let (a, b, c, d) = (t[i][i], t[i][i + 1], t[i + 1][i], t[i + 1][i + 1]);
+            let mean = (a + d) / two;
+            let half = (a - d) / two;
+            let discriminant = half * half + b * c;
+            let (first, second) = if discriminant < K::zero() {
+                let im = (-discriminant).sqrt();
+                (Complex::new(mean, im), Complex::new(mean, -im))
+            } else {
+                let re = discriminant.sqrt();
+                (real(mean + re), real(mean - re))
+            };
+            result[i] = (first, i, 2);
+            result[i + 1] = (second, i, 2);
+            i += 2;
+        } else {
+            result[i] = (real(t[i][i]), i, 1);
+            i += 1;
+        }
+    }
+    result
+}
+
+/// Solves `(T - λ·I)·y = 0` upwards from the block of `λ`; zero pivots are
+/// replaced by `tiny`, as for repeated eigenvalues.
+fn schur_eigenvector<K: Real, const N: usize>(
+    t: &Matrix<K, N, N>,
+    lambda: Complex<K>,
+    start: usize,
+    size: usize,
+    tiny: K,
+) -> [Complex<K>; N]
+where
+    Complex<K>: Field,
+{
+    let nonzero = |x: Complex<K>| if x == Complex::zero() { real(tiny) } else { x };
+    let mut y = [Complex::zero(); N];
+    if size == 1 {
+        y[start] = Complex::one();
+    } else {
+        let (a, b, c, d) = (
+            t[start][start],
+            t[start][start + 1],
+            t[start + 1][start],
+            t[start + 1][start + 1],
+        );
+        if b != K::zero() {
+            y[start] = real(b);
+            y[start + 1] = lambda - real(a);
+        } else {
+            y[start] = lambda - real(d);
+            y[start + 1] = real(c);
+        }
+    }
+
+    let residual = |y: &[Complex<K>; N], row: usize, from: usize| {
+        (from..N).fold(Complex::zero(), |acc, j| acc - real(t[row][j]) * y[j])
+    };
+    let mut i = start;
+    while i > 0 {
+        let bottom = i - 1;
+        if bottom > 0 && t[bottom][bottom - 1] != K::zero() {
+            let top = bottom - 1;
+            let (f, g) = (residual(&y, top, i), residual(&y, bottom, i));
+            let (p, q) = (real(t[top][top]) - lambda, real(t[top][bottom]));
+            let (r, s) = (real(t[bottom][top]), real(t[bottom][bottom]) - lambda);
+            let det = nonzero(p * s - q * r);
+            y[top] = (s * f - q * g) / det;
+            y[bottom] = (p * g - r * f) / det;
+            i -= 2;
+        } else {
+            y[bottom] = residual(&y, bottom, i) / nonzero(real(t[bottom][bottom]) - lambda);
+            i -= 1;
+        }
+    }
+    y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use nalgebra::{DMatrix, SymmetricEigen as NalgebraEigen};
+    use rand::prelude::*;
+
+    const NB_TESTCASE_MATRICES: usize = 100;
+    const THRESHOLD: f64 = 1e-8;
+
+    fn generate_random_symmetric<const N: usize>() -> Matrix<f64, N, N> {
+        let mut rng = rand::thread_rng();
+        let mut data = [[0.0; N]; N];
+        for row in &mut data {
+            for elem in row {
+                *elem = rng.gen_range(-1.0..1.0);
+            }
+        }
+        let a = Matrix::new(data);
+        a + a.transpose()
+    }
+
+    fn test_symmetric_eigen<const N: usize>(a: &Matrix<f64, N, N>) {
+        let eigen = a.symmetric_eigen().unwrap();
+        let (values, vectors) = (eigen.eigenvalues(), eigen.eigenvectors());
+
+        let mut expected: Vec<f64> = NalgebraEigen::new(DMatrix::from_fn(N, N, |i, j| a[i][j]))
+            .eigenvalues
+            .iter()
+            .copied()
+            .collect();
+        expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        for i in 0..N {
+            assert_abs_diff_eq!(values[i], expected[i], epsilon = THRESHOLD);
+        }
+
+        let av = a.mul_mat(vectors);
+        let vtv = vectors.transpose().mul_mat(vectors);
+        let recomposed = eigen.recompose();
+        for i in 0..N {
+            for j in 0..N {
+                assert_abs_diff_eq!(av[i][j], vectors[i][j] * values[j], epsilon = THRESHOLD);
+                let identity = if i == j { 1. } else { 0. };
+                assert_abs_diff_eq!(vtv[i][j], identity, epsilon = THRESHOLD);
+                assert_abs_diff_eq!(recomposed[i][j], a[i][j], epsilon = THRESHOLD);
+            }
+        }
+    }
+
+    #[test]
+    fn symmetric_eigen_4() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            test_symmetric_eigen(&generate_random_symmetric::<4>());
+        }
+    }
+
+    #[test]
+    fn symmetric_eigen_8() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            test_symmetric_eigen(&generate_random_symmetric::<8>());
+        }
+    }
+
+    #[test]
+    fn symmetric_eigen_repeated() {
+        let identity = Matrix::<f64, 3, 3>::identity();
+        test_symmetric_eigen(&identity);
+        test_symmetric_eigen(&Matrix::new([[0.; 3]; 3]));
+
+        let a = Matrix::new([[2., 1., 0.], [1., 2., 0.], [0., 0., 3.]]);
+        test_symmetric_eigen(&a);
+        assert_eq!(
+            a.symmetric_eigenvalues().map(|v| v.map(f64::round)),
+            Ok([1., 3., 3.])
+        );
+    }
+
+    #[test]
+    fn symmetric_eigen_no_convergence() {
+        let a = Matrix::new([[1., f64::NAN], [f64::NAN, 1.]]);
+        assert_eq!(
+            a.symmetric_eigen().unwrap_err(),
+            EigenError::NoConvergence(MAX_SWEEPS)
+        );
+    }
+
+    fn generate_random_matrix<const N: usize>() -> Matrix<f64, N, N> {
+        let mut rng = rand::thread_rng();
+        let mut data = [[0.0; N]; N];
+        for row in &mut data {
+            for elem in row {
+                *elem = rng.gen_range(-1.0..1.0);
+            }
+        }
+        Matrix::new(data)
+    }
+
+    fn assert_orthogonal_similarity<const N: usize>(
+        a: &Matrix<f64, N, N>,
+        q: &Matrix<f64, N, N>,
+        t: &Matrix<f64, N, N>,
+    ) {
+        let recomposed = q.mul_mat(t).mul_mat(&q.transpose());
+        let qtq = q.transpose().mul_mat(q);
+        for i in 0..N {
+            for j in 0..N {
+                assert_abs_diff_eq!(recomposed[i][j], a[i][j], epsilon = THRESHOLD);
+                let identity = if i == j { 1. } else { 0. };
+                assert_abs_diff_eq!(qtq[i][j], identity, epsilon = THRESHOLD);
+                if i > j + 1 {
+                    assert_eq!(t[i][j], 0.);
+                }
+            }
+        }
+    }
+
+    fn test_eigen<const N: usize>(a: &Matrix<f64, N, N>) {
+        let (q, h) = a.hessenberg();
+        assert_orthogonal_similarity(a, &q, &h);
+
+        let (q, t) = a.schur().unwrap();
+        assert_orthogonal_similarity(a, &q, &t);
+        for i in 1..N - 1 {
+            // 2×2 blocks never overlap.
+            assert!(t[i][i - 1] == 0. || t[i + 1][i] == 0.);
+        }
+
+        let mut expected: Vec<Complex<f64>> = DMatrix::from_fn(N, N, |i, j| a[i][j])
+            .complex_eigenvalues()
+            .iter()
+            .copied()
+            .collect();
+        expected.sort_by(compare_complex);
+        let eigen = a.eigen().unwrap();
+        let (values, vectors) = (eigen.eigenvalues(), eigen.eigenvectors());
+        assert_eq!(&a.eigenvalues().unwrap(), values);
+        for i in 0..N {
+            assert_abs_diff_eq!(values[i].re, expected[i].re, epsilon = 1e-6);
+            assert_abs_diff_eq!(values[i].im, expected[i].im, epsilon = 1e-6);
+        }
+
+        for (j, &lambda) in values.iter().enumerate() {
+            let norm = (0..N).map(|i| vectors[i][j].norm_sqr()).sum::<f64>();
+            assert_abs_diff_eq!(norm, 1., epsilon = THRESHOLD);
+            for i in 0..N {
+                let ax = (0..N).fold(Complex::<f64>::zero(), |acc, k| {
+                    acc + vectors[k][j] * a[i][k]
+                });
+                let residual = ax - vectors[i][j] * lambda;
+                assert_abs_diff_eq!(residual.norm(), 0., epsilon = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn eigen_4() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            test_eigen(&generate_random_matrix::<4>());
+        }
+    }
+
+    #[test]
+    fn eigen_7() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            test_eigen(&generate_random_matrix::<7>());
+        }
+    }
+
+    #[test]
+    fn eigen_special() {
+        let rotation = Matrix::new([[0., -1.], [1., 0.]]);
+        test_eigen(&rotation);
+        assert_eq!(
+            rotation.eigenvalues(),
+            Ok([Complex::new(0., -1.), Complex::new(0., 1.)])
+        );
+
+        test_eigen(&Matrix::<f64, 3, 3>::identity());
+        // nalgebra's Schur never terminates on the zero matrix.
+        let zero = Matrix::new([[0.; 3]; 3]);
+        assert_eq!(zero.eigenvalues(), Ok([Complex::zero(); 3]));
+        let eigen = Matrix::<f64, 3, 3>::identity().eigen().unwrap();
+        assert_eq!(eigen.eigenvectors(), &Matrix::identity());
+
+        let symmetric = generate_random_symmetric::<5>();
+        test_eigen(&symmetric);
+        let expected = symmetric.symmetric_eigenvalues().unwrap();
+        for (lambda, expected) in symmetric.eigenvalues().unwrap().iter().zip(expected) {
+            assert_abs_diff_eq!(lambda.re, expected, epsilon = THRESHOLD);
+            assert_eq!(lambda.im, 0.);
+        }
+
+        let nan = Matrix::new([[1., 2., 3.], [f64::NAN, 1., 2.], [0., 1., 1.]]);
+        assert!(matches!(
+            nan.eigenvalues(),
+            Err(EigenError::NoConvergence(_))
+        ));
+    }
+}
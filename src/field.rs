@@ -0,0 +1,180 @@
+use num_complex::Complex;
+use num_integer::Integer;
+use num_rational::Ratio;
+use num_traits::{float::Float, NumAssign, One, Signed, ToPrimitive, Zero};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Scalars closed under addition, subtraction and multiplication.
+pub trait Ring:
+    Add<Output = Self>
+    + AddAssign
+    + Add
+    + Sub<Output = Self>
+    + SubAssign
+    + Sub
+    + Mul<Output = Self>
+    + MulAssign
+    + Mul
+    + Neg<Output = Self>
+    + PartialEq
+    + Copy
+    + Zero
+    + One
+{
+    fn conjugate(self) -> Self {
+        self
+    }
+
+    /// Whether `self` makes a better elimination pivot than `current`. Without
+    /// an absolute value the first non-zero candidate wins. Any candidate that
+    /// is not exactly zero must beat zero itself, so eliminations may treat a
+    /// zero best pivot as a zero column.
+    fn is_better_pivot(self, current: Self) -> bool {
+        current.is_zero() && !self.is_zero()
+    }
+
+    /// Whether `self` cannot be told apart from zero, e.g. an interval
+    /// straddling it. Exact scalars only answer this for zero itself.
+    fn may_be_zero(self) -> bool {
+        self.is_zero()
+    }
+}
+
+/// Rings in which every non-zero element has a multiplicative inverse.
+pub trait Field: Ring + Div<Output = Self> + DivAssign + Div {}
+
+/// Fields measured by a real absolute value, as needed by norms and angles.
+pub trait RealField: Field {
+    fn modulus(self) -> f64;
+
+    fn real(self) -> f64;
+}
+
+/// Ordered real scalars supporting the elementary functions used by geometry
+/// helpers such as `projection`.
+pub trait Real: Field + PartialOrd {
+    fn from_f64(value: f64) -> Self;
+
+    fn abs(self) -> Self;
+
+    fn sqrt(self) -> Self;
+
+    fn sin(self) -> Self;
+
+    fn cos(self) -> Self;
+
+    fn tan(self) -> Self;
+
+    fn exp(self) -> Self;
+
+    fn ln(self) -> Self;
+}
+
+macro_rules! impl_ring_integer {
+    ($($t:ty),*) => {
+        $(impl Ring for $t {})*
+    };
+}
+
+impl_ring_integer!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_real_float {
+    ($($t:ty),*) => {
+        $(
+            impl Ring for $t {
+                fn is_better_pivot(self, current: Self) -> bool {
+                    self.abs() > current.abs()
+                }
+            }
+
+            impl Field for $t {}
+
+            impl RealField for $t {
+                fn modulus(self) -> f64 {
+                    f64::from(self).abs()
+                }
+
+                fn real(self) -> f64 {
+                    f64::from(self)
+                }
+            }
+
+            impl Real for $t {
+                fn from_f64(value: f64) -> Self {
+                    value as $t
+                }
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn sqrt(self) -> Self {
+                    <$t>::sqrt(self)
+                }
+
+                fn sin(self) -> Self {
+                    <$t>::sin(self)
+                }
+
+                fn cos(self) -> Self {
+                    <$t>::cos(self)
+                }
+
+                fn tan(self) -> Self {
+                    <$t>::tan(self)
+                }
+
+                fn exp(self) -> Self {
+                    <$t>::exp(self)
+                }
+
+                fn ln(self) -> Self {
+                    <$t>::ln(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_real_float!(f32, f64);
+
+impl<T: Float + NumAssign + Into<f64>> Ring for Complex<T> {
+    fn conjugate(self) -> Self {
+        self.conj()
+    }
+
+    fn is_better_pivot(self, current: Self) -> bool {
+        self.norm_sqr() > current.norm_sqr()
+    }
+}
+
+impl<T: Float + NumAssign + Into<f64>> Field for Complex<T> {}
+
+impl<T: Float + NumAssign + Into<f64>> RealField for Complex<T> {
+    fn modulus(self) -> f64 {
+        self.norm().into()
+    }
+
+    fn real(self) -> f64 {
+        self.re.into()
+    }
+}
+
+impl<T: Integer + Signed + NumAssign + ToPrimitive + Copy> Ring for Ratio<T> {
+    fn is_better_pivot(self, current: Self) -> bool {
+        self.abs() > current.abs()
+    }
+}
+
+impl<T: Integer + Signed + NumAssign + ToPrimitive + Copy> Field for Ratio<T> {}
+
+impl<T: Integer + Signed + NumAssign + ToPrimitive + Copy> RealField for Ratio<T> {
+    fn modulus(self) -> f64 {
+        self.abs().real()
+    }
+
+    fn real(self) -> f64 {
+        let (numer, denom) = (self.numer().to_f64(), self.denom().to_f64());
+        numer.zip(denom).map_or(f64::NAN, |(n, d)| n / d)
+    }
+}
@@ -0,0 +1,282 @@
+use crate::field::{Field, Real, Ring};
+use crate::matrix::Matrix;
+
+const PADE_DEGREE: usize = 6;
+const MAX_ITERATIONS: usize = 100;
+
+impl<K: Ring, const N: usize> Matrix<K, N, N> {
+    /// `self^exponent` by repeated squaring, `self^0` being the identity.
+    pub fn pow(&self, mut exponent: u32) -> Self {
+        let mut result = Self::identity();
+        let mut square = *self;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul_mat(&square);
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                square = square.mul_mat(&square);
+            }
+        }
+        result
+    }
+}
+
+impl<K: Field, const N: usize> Matrix<K, N, N> {
+    /// Like `pow`, with negative exponents raising the inverse.
+    pub fn powi(&self, exponent: i32) -> Result<Self, &'static str> {
+        if exponent < 0 {
+            Ok(self.inverse()?.pow(exponent.unsigned_abs()))
+        } else {
+            Ok(self.pow(exponent.unsigned_abs()))
+        }
+    }
+}
+
+/// Maximum absolute row sum, in `K` so that it works for any `Real`.
+fn norm_inf<K: Real, const N: usize>(matrix: &Matrix<K, N, N>) -> K {
+    matrix
+        .iter()
+        .map(|row| row.iter().fold(K::zero(), |acc, &x| acc + x.abs()))
+        .fold(K::zero(), |acc, x| if x > acc { x } else { acc })
+}
+
+impl<K: Real, const N: usize> Matrix<K, N, N> {
+    /// Matrix exponential by scaling and squaring: `e^A = (e^(A/2^s))^(2^s)`
+    /// with `‖A/2^s‖∞ ≤ 1/2` and the inner exponential from its diagonal
+    /// Padé approximant of degree 6 (Golub & Van Loan, algorithm 11.3.1).
+    pub fn expm(&self) -> Self {
+        let half = K::from_f64(0.5);
+        let mut a = *self;
+        let mut norm = norm_inf(&a);
+        // Halving an infinite or NaN norm never brings it under 1/2, so such
+        // a matrix gets the NaN that `norm · 0` gives in every entry.
+        let undefined = norm * K::zero();
+        if undefined != K::zero() {
+            return Matrix::new([[undefined; N]; N]);
+        }
+        let mut squarings = 0;
+        while norm > half {
+            norm *= half;
+            a.scl(half);
+            squarings += 1;
+        }
+
+        let identity = Self::identity();
+        let mut power = identity;
+        let mut numerator = identity;
+        let mut denominator = identity;
+        let mut c = K::one();
+        for k in 1..=PADE_DEGREE {
+            c = c * K::from_f64((PADE_DEGREE - k + 1) as f64)
+                / K::from_f64((k * (2 * PADE_DEGREE - k + 1)) as f64);
+            power = a.mul_mat(&power);
+            let mut term = power;
+            term.scl(c);
+            numerator += term;
+            if k % 2 == 0 {
+                denominator += term;
+            } else {
+                denominator -= term;
+            }
+        }
+
+        let mut result = denominator
+            .lu()
+            .solve_mat(&numerator)
+            .expect("Padé denominator is nonsingular for ‖A‖ ≤ 1/2");
+        for _ in 0..squarings {
+            result = result.mul_mat(&result);
+        }
+        result
+    }
+
+    /// Principal square root by the Denman–Beavers iteration
+    /// `Y ← (Y + Z⁻¹)/2, Z ← (Z + Y⁻¹)/2`, with `Y → √A` and `Z → √A⁻¹`.
+    /// Needs `A` to have no eigenvalue on the closed negative real axis.
+    pub fn sqrtm(&self) -> Result<Self, &'static str> {
+        let half = K::from_f64(0.5);
+        let tolerance = K::from_f64(1e-13);
+        let mut y = *self;
+        let mut z = Self::identity();
+
+        for _ in 0..MAX_ITERATIONS {
+            let mut next_y = y + z.inverse()?;
+            let mut next_z = z + y.inverse()?;
+            next_y.scl(half);
+            next_z.scl(half);
+
+            let change = norm_inf(&(next_y - y));
+            y = next_y;
+            z = next_z;
+            if change <= tolerance * norm_inf(&y) {
+                return Ok(y);
+            }
+        }
+        Err("Square root iteration did not converge")
+    }
+
+    /// Principal logarithm by inverse scaling and squaring: square roots are
+    /// taken until `A^(1/2^k)` is close to the identity, where the series of
+    /// `log(I + X)` converges quickly, and the result is scaled by `2^k`.
+    pub fn logm(&self) -> Result<Self, &'static str> {
+        let identity = Self::identity();
+        let quarter = K::from_f64(0.25);
+        let epsilon = K::from_f64(f64::EPSILON);
+        let mut a = *self;
+        let mut roots = 0;
+        while norm_inf(&(a - identity)) > quarter {
+            if roots == MAX_ITERATIONS {
+                return Err("Logarithm scaling did not converge");
+            }
+            a = a.sqrtm()?;
+            roots += 1;
+        }
+
+        let x = a - identity;
+        let mut power = identity;
+        let mut result = Matrix::new([[K::zero(); N]; N]);
+        for j in 1..=MAX_ITERATIONS {
+            power = power.mul_mat(&x);
+            let mut term = power;
+            term.scl(K::one() / K::from_f64(j as f64));
+            if j % 2 == 0 {
+                result -= term;
+            } else {
+                result += term;
+            }
+            if norm_inf(&term) <= epsilon * norm_inf(&result) {
+                break;
+            }
+        }
+
+        for _ in 0..roots {
+            result.scl(K::one() + K::one());
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use nalgebra::DMatrix;
+    use rand::prelude::*;
+
+    const NB_TESTCASE_MATRICES: usize = 100;
+    const THRESHOLD: f64 = 1e-8;
+
+    fn generate_random_matrix<const N: usize>(range: f64) -> Matrix<f64, N, N> {
+        let mut rng = rand::thread_rng();
+        let mut data = [[0.0; N]; N];
+        for row in &mut data {
+            for elem in row {
+                *elem = rng.gen_range(-range..range);
+            }
+        }
+        Matrix::new(data)
+    }
+
+    fn assert_matrix_eq<const N: usize>(matrix: &Matrix<f64, N, N>, expected: &Matrix<f64, N, N>) {
+        for i in 0..N {
+            for j in 0..N {
+                assert_abs_diff_eq!(matrix[i][j], expected[i][j], epsilon = THRESHOLD);
+            }
+        }
+    }
+
+    #[test]
+    fn matrix_pow() {
+        let fibonacci = Matrix::new([[1, 1], [1, 0]]);
+        assert_eq!(fibonacci.pow(0), Matrix::identity());
+        assert_eq!(fibonacci.pow(1), fibonacci);
+        assert_eq!(fibonacci.pow(10), Matrix::new([[89, 55], [55, 34]]));
+
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let a = generate_random_matrix::<4>(1.);
+            let cube = a.mul_mat(&a).mul_mat(&a);
+            assert_matrix_eq(&a.pow(3), &cube);
+            if a.determinant().abs() > 0.1 {
+                let inverse = a.inverse().unwrap();
+                let expected = inverse.mul_mat(&inverse).mul_mat(&inverse);
+                let result = a.powi(-3).unwrap();
+                for i in 0..4 {
+                    for j in 0..4 {
+                        let scale = expected[i][j].abs().max(1.);
+                        assert_abs_diff_eq!(
+                            result[i][j] / scale,
+                            expected[i][j] / scale,
+                            epsilon = 1e-8
+                        );
+                    }
+                }
+            }
+        }
+
+        let singular = Matrix::new([[1., 2.], [2., 4.]]);
+        assert_eq!(singular.powi(2), Ok(singular.mul_mat(&singular)));
+        assert_eq!(singular.powi(-1), Err("Matrix is singular"));
+    }
+
+    #[test]
+    fn matrix_expm() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let a = generate_random_matrix::<4>(2.);
+            let expected = DMatrix::from_fn(4, 4, |i, j| a[i][j]).exp();
+            let result = a.expm();
+            for i in 0..4 {
+                for j in 0..4 {
+                    let scale = expected[(i, j)].abs().max(1.);
+                    assert_abs_diff_eq!(
+                        result[i][j] / scale,
+                        expected[(i, j)] / scale,
+                        epsilon = 1e-10
+                    );
+                }
+            }
+        }
+
+        let zero = Matrix::new([[0.; 3]; 3]);
+        assert_eq!(zero.expm(), Matrix::identity());
+        let t = 1.3_f64;
+        let rotation = Matrix::new([[0., -t], [t, 0.]]).expm();
+        assert_matrix_eq(
+            &rotation,
+            &Matrix::new([[t.cos(), -t.sin()], [t.sin(), t.cos()]]),
+        );
+
+        for value in [f64::INFINITY, f64::NEG_INFINITY, f64::NAN] {
+            let result = Matrix::new([[1., value], [0., 1.]]).expm();
+            assert!(result.iter().flatten().all(|x| x.is_nan()));
+        }
+    }
+
+    #[test]
+    fn matrix_sqrtm_logm() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let b = generate_random_matrix::<4>(1.);
+            let spd = b.transpose().mul_mat(&b) + Matrix::identity();
+            let root = spd.sqrtm().unwrap();
+            assert_matrix_eq(&root.mul_mat(&root), &spd);
+
+            let a = generate_random_matrix::<4>(0.5);
+            assert_matrix_eq(&a.expm().logm().unwrap(), &a);
+            assert_matrix_eq(&spd.logm().unwrap().expm(), &spd);
+        }
+
+        let diagonal = Matrix::new([[4., 0.], [0., 9.]]);
+        assert_matrix_eq(
+            &diagonal.sqrtm().unwrap(),
+            &Matrix::new([[2., 0.], [0., 3.]]),
+        );
+        let log = Matrix::new([[1f64.exp(), 0.], [0., 2f64.exp()]])
+            .logm()
+            .unwrap();
+        assert_matrix_eq(&log, &Matrix::new([[1., 0.], [0., 2.]]));
+
+        let singular = Matrix::new([[1., 2.], [2., 4.]]);
+        assert_eq!(singular.sqrtm(), Err("Matrix is singular"));
+        assert!(singular.logm().is_err());
+    }
+}
@@ -0,0 +1,298 @@
+use crate::field::{Field, Ring};
+
+use num_traits::{One, Zero};
+use std::fmt::{Display, Formatter, Result};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+const fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+const fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Miller–Rabin with the first twelve primes as witnesses, which is exact
+/// for every `u64` and cheap enough for const evaluation.
+const fn is_prime(p: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if p < 2 {
+        return false;
+    }
+    let mut i = 0;
+    while i < WITNESSES.len() {
+        if p == WITNESSES[i] {
+            return true;
+        }
+        if p.is_multiple_of(WITNESSES[i]) {
+            return false;
+        }
+        i += 1;
+    }
+
+    let shift = (p - 1).trailing_zeros();
+    let odd = (p - 1) >> shift;
+    let mut i = 0;
+    while i < WITNESSES.len() {
+        let mut x = pow_mod(WITNESSES[i], odd, p);
+        let mut round = 1;
+        let mut composite = x != 1 && x != p - 1;
+        while composite && round < shift {
+            x = mul_mod(x, x, p);
+            composite = x != p - 1;
+            round += 1;
+        }
+        if composite {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Element of the prime field GF(P), stored as its representative in `0..P`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Gf<const P: u64>(u64);
+
+impl<const P: u64> Gf<P> {
+    pub fn new(value: u64) -> Self {
+        const { assert!(is_prime(P), "Gf modulus must be prime") };
+        Gf(value % P)
+    }
+
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut base = self;
+        let mut result = Self::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    pub fn inverse(self) -> Option<Self> {
+        if self.0 == 0 {
+            None
+        } else {
+            Some(self.pow(P - 2))
+        }
+    }
+}
+
+impl<const P: u64> From<u64> for Gf<P> {
+    fn from(value: u64) -> Self {
+        Gf::new(value)
+    }
+}
+
+impl<const P: u64> From<i64> for Gf<P> {
+    fn from(value: i64) -> Self {
+        Gf::new((value as i128).rem_euclid(P as i128) as u64)
+    }
+}
+
+impl<const P: u64> Display for Gf<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const P: u64> Add for Gf<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Gf(((self.0 as u128 + rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> AddAssign for Gf<P> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const P: u64> Sub for Gf<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl<const P: u64> SubAssign for Gf<P> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const P: u64> Mul for Gf<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Gf(((self.0 as u128 * rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> MulAssign for Gf<P> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const P: u64> Div for Gf<P> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse().expect("Division by zero error")
+    }
+}
+
+impl<const P: u64> DivAssign for Gf<P> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const P: u64> Neg for Gf<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        if self.0 == 0 {
+            self
+        } else {
+            Gf(P - self.0)
+        }
+    }
+}
+
+impl<const P: u64> Zero for Gf<P> {
+    fn zero() -> Self {
+        Gf::new(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const P: u64> One for Gf<P> {
+    fn one() -> Self {
+        Gf::new(1)
+    }
+}
+
+impl<const P: u64> Ring for Gf<P> {}
+
+impl<const P: u64> Field for Gf<P> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::Matrix;
+    use rand::prelude::*;
+
+    type F7 = Gf<7>;
+    type F101 = Gf<101>;
+
+    const NB_TESTCASE_MATRICES: usize = 100;
+
+    fn generate_random_matrix<const N: usize>() -> Matrix<F101, N, N> {
+        let mut rng = rand::thread_rng();
+        let mut data = [[F101::zero(); N]; N];
+        for row in &mut data {
+            for elem in row {
+                *elem = F101::new(rng.gen_range(0..101));
+            }
+        }
+        Matrix::new(data)
+    }
+
+    #[test]
+    fn gf_arithmetic() {
+        assert_eq!(F7::new(5) + F7::new(4), F7::new(2));
+        assert_eq!(F7::new(2) - F7::new(5), F7::new(4));
+        assert_eq!(F7::new(3) * F7::new(5), F7::new(1));
+        assert_eq!(F7::new(1) / F7::new(3), F7::new(5));
+        assert_eq!(F7::from(-1i64), F7::new(6));
+        assert_eq!(F7::new(0).inverse(), None);
+        for a in 1..7 {
+            assert_eq!(F7::new(a) * F7::new(a).inverse().unwrap(), F7::one());
+        }
+    }
+
+    #[test]
+    fn gf_large_modulus() {
+        // The largest prime below 2⁶⁴, which does not fit in an `i64`.
+        type Big = Gf<18_446_744_073_709_551_557>;
+        assert_eq!(Big::from(-1i64), Big::new(18_446_744_073_709_551_556));
+        assert_eq!(
+            Big::from(i64::MIN).value(),
+            18_446_744_073_709_551_557 - (1 << 63)
+        );
+        let a = Big::new(1 << 63);
+        assert_eq!(a * a.inverse().unwrap(), Big::one());
+
+        assert!(is_prime(2) && is_prime(3) && is_prime(37) && is_prime(41));
+        assert!(!is_prime(0) && !is_prime(1) && !is_prime(91));
+        // Strong pseudoprime to bases 2 through 11.
+        assert!(!is_prime(3_215_031_751));
+        assert!(is_prime(9_223_372_036_854_775_783));
+        assert!(!is_prime(9_223_372_036_854_775_807));
+        let sieve = |n: u64| {
+            n >= 2
+                && (2..n)
+                    .take_while(|d| d * d <= n)
+                    .all(|d| !n.is_multiple_of(d))
+        };
+        for n in 0..2000 {
+            assert_eq!(is_prime(n), sieve(n));
+        }
+    }
+
+    #[test]
+    fn gf_matrix_inverse() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let u = generate_random_matrix::<5>();
+            match u.inverse() {
+                Ok(inverse) => {
+                    assert_eq!(u.rank(), 5);
+                    assert_eq!(u.mul_mat(&inverse), Matrix::identity());
+                    assert_ne!(u.determinant(), F101::zero());
+                }
+                Err(_) => {
+                    assert!(u.rank() < 5);
+                    assert_eq!(u.determinant(), F101::zero());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn gf_rank() {
+        // Over the integers this determinant is -21, which vanishes modulo 7.
+        let u = Matrix::from([[1u64, 2, 3], [4, 5, 6], [7, 8, 16]].map(|row| row.map(F7::new)));
+        assert_eq!(u.rank(), 2);
+        assert_eq!(u.determinant(), F7::zero());
+        assert!(u.inverse().is_err());
+
+        let u = Matrix::from([[0u64, 1], [1, 0]].map(|row| row.map(F7::new)));
+        assert_eq!(u.inverse(), Ok(u));
+    }
+}
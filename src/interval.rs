@@ -0,0 +1,467 @@
+use crate::dvector::DVector;
+use crate::field::{Field, Real, Ring};
+use crate::vector::Vector;
+
+use num_traits::{One, Zero};
+use std::cmp::Ordering;
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+use std::fmt::{Display, Formatter, Result};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Closed interval `[lo, hi]` of reals. Every operation rounds its bounds
+/// outward, so the result always encloses the exact value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    lo: f64,
+    hi: f64,
+}
+
+fn down(x: f64) -> f64 {
+    x.next_down()
+}
+
+fn up(x: f64) -> f64 {
+    x.next_up()
+}
+
+impl Interval {
+    pub const ENTIRE: Interval = Interval {
+        lo: f64::NEG_INFINITY,
+        hi: f64::INFINITY,
+    };
+
+    pub fn new(lo: f64, hi: f64) -> Self {
+        assert!(lo <= hi, "Interval bounds are reversed");
+        Interval { lo, hi }
+    }
+
+    pub fn point(value: f64) -> Self {
+        Interval::new(value, value)
+    }
+
+    pub fn lo(&self) -> f64 {
+        self.lo
+    }
+
+    pub fn hi(&self) -> f64 {
+        self.hi
+    }
+
+    pub fn width(&self) -> f64 {
+        up(self.hi - self.lo)
+    }
+
+    pub fn midpoint(&self) -> f64 {
+        self.lo / 2.0 + self.hi / 2.0
+    }
+
+    pub fn contains(&self, value: f64) -> bool {
+        self.lo <= value && value <= self.hi
+    }
+
+    /// Smallest absolute value over the interval.
+    pub fn mignitude(&self) -> f64 {
+        if self.contains(0.0) {
+            0.0
+        } else {
+            self.lo.abs().min(self.hi.abs())
+        }
+    }
+
+    /// Largest absolute value over the interval.
+    pub fn magnitude(&self) -> f64 {
+        self.lo.abs().max(self.hi.abs())
+    }
+
+    pub fn max(self, other: Self) -> Self {
+        Interval::new(self.lo.max(other.lo), self.hi.max(other.hi))
+    }
+
+    /// Libm results are not correctly rounded, so widen them by a few ulps.
+    fn widen(lo: f64, hi: f64) -> Self {
+        Interval::new(down(down(lo)), up(up(hi)))
+    }
+
+    /// Whether some `offset + k·TAU` lies in the interval.
+    fn reaches(&self, offset: f64) -> bool {
+        let k = ((self.lo - offset) / TAU).ceil();
+        offset + k * TAU <= self.hi
+    }
+}
+
+impl From<f64> for Interval {
+    fn from(value: f64) -> Self {
+        Interval::point(value)
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match f.precision() {
+            Some(precision) => write!(f, "[{:.*}, {:.*}]", precision, self.lo, precision, self.hi),
+            None => write!(f, "[{}, {}]", self.lo, self.hi),
+        }
+    }
+}
+
+/// Ordered only when one interval lies entirely below the other.
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other && self.lo == self.hi {
+            Some(Ordering::Equal)
+        } else if self.hi < other.lo {
+            Some(Ordering::Less)
+        } else if self.lo > other.hi {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+impl Add for Interval {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Interval::new(down(self.lo + rhs.lo), up(self.hi + rhs.hi))
+    }
+}
+
+impl AddAssign for Interval {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Interval {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Interval::new(down(self.lo - rhs.hi), up(self.hi - rhs.lo))
+    }
+}
+
+impl SubAssign for Interval {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for Interval {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return Interval::zero();
+        }
+
+        let products = [
+            self.lo * rhs.lo,
+            self.lo * rhs.hi,
+            self.hi * rhs.lo,
+            self.hi * rhs.hi,
+        ];
+        let lo = products.iter().copied().fold(f64::INFINITY, f64::min);
+        let hi = products.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        Interval::new(down(lo), up(hi))
+    }
+}
+
+impl MulAssign for Interval {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div for Interval {
+    type Output = Self;
+
+    /// Dividing by an interval containing zero encloses nothing tighter than
+    /// the whole real line.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.contains(0.0) {
+            return Interval::ENTIRE;
+        }
+
+        let quotients = [
+            self.lo / rhs.lo,
+            self.lo / rhs.hi,
+            self.hi / rhs.lo,
+            self.hi / rhs.hi,
+        ];
+        let lo = quotients.iter().copied().fold(f64::INFINITY, f64::min);
+        let hi = quotients.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        Interval::new(down(lo), up(hi))
+    }
+}
+
+impl DivAssign for Interval {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Neg for Interval {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Interval::new(-self.hi, -self.lo)
+    }
+}
+
+impl Zero for Interval {
+    fn zero() -> Self {
+        Interval::point(0.0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.lo == 0.0 && self.hi == 0.0
+    }
+}
+
+impl One for Interval {
+    fn one() -> Self {
+        Interval::point(1.0)
+    }
+}
+
+impl Ring for Interval {
+    /// By mignitude, then by magnitude: every interval straddling zero has
+    /// mignitude zero, and the wider one must still beat the point zero.
+    fn is_better_pivot(self, current: Self) -> bool {
+        (self.mignitude(), self.magnitude()) > (current.mignitude(), current.magnitude())
+    }
+
+    fn may_be_zero(self) -> bool {
+        self.contains(0.0)
+    }
+}
+
+impl Field for Interval {}
+
+impl Real for Interval {
+    fn from_f64(value: f64) -> Self {
+        Interval::point(value)
+    }
+
+    fn abs(self) -> Self {
+        if self.lo >= 0.0 {
+            self
+        } else if self.hi <= 0.0 {
+            -self
+        } else {
+            Interval::new(0.0, self.magnitude())
+        }
+    }
+
+    fn sqrt(self) -> Self {
+        assert!(self.hi >= 0.0, "Square root of a negative interval");
+        Interval::widen(self.lo.max(0.0).sqrt(), self.hi.sqrt()).max(Interval::zero())
+    }
+
+    fn sin(self) -> Self {
+        if self.width() >= TAU {
+            return Interval::new(-1.0, 1.0);
+        }
+
+        let (a, b) = (self.lo.sin(), self.hi.sin());
+        let lo = if self.reaches(-FRAC_PI_2) {
+            -1.0
+        } else {
+            a.min(b)
+        };
+        let hi = if self.reaches(FRAC_PI_2) {
+            1.0
+        } else {
+            a.max(b)
+        };
+        Interval::widen(lo, hi)
+    }
+
+    fn cos(self) -> Self {
+        if self.width() >= TAU {
+            return Interval::new(-1.0, 1.0);
+        }
+
+        let (a, b) = (self.lo.cos(), self.hi.cos());
+        let lo = if self.reaches(PI) { -1.0 } else { a.min(b) };
+        let hi = if self.reaches(0.0) { 1.0 } else { a.max(b) };
+        Interval::widen(lo, hi)
+    }
+
+    fn tan(self) -> Self {
+        let branch = |x: f64| ((x + FRAC_PI_2) / PI).floor();
+        if self.width() >= PI || branch(self.lo) != branch(self.hi) {
+            return Interval::ENTIRE;
+        }
+
+        Interval::widen(self.lo.tan(), self.hi.tan())
+    }
+
+    fn exp(self) -> Self {
+        Interval::widen(self.lo.exp(), self.hi.exp()).max(Interval::zero())
+    }
+
+    fn ln(self) -> Self {
+        assert!(self.hi > 0.0, "Logarithm of a non-positive interval");
+        let lo = if self.lo > 0.0 {
+            self.lo.ln()
+        } else {
+            f64::NEG_INFINITY
+        };
+        Interval::widen(lo, self.hi.ln())
+    }
+}
+
+impl<const N: usize> Vector<Interval, N> {
+    pub fn norm_1(&self) -> Interval {
+        self.iter().fold(Interval::zero(), |acc, &x| acc + x.abs())
+    }
+
+    pub fn norm(&self) -> Interval {
+        self.dot(self).sqrt()
+    }
+
+    pub fn norm_inf(&self) -> Interval {
+        self.iter()
+            .fold(Interval::zero(), |max, &x| max.max(x.abs()))
+    }
+}
+
+impl DVector<Interval> {
+    pub fn norm_1(&self) -> Interval {
+        self.iter().fold(Interval::zero(), |acc, &x| acc + x.abs())
+    }
+
+    pub fn norm(&self) -> Interval {
+        self.dot(self).sqrt()
+    }
+
+    pub fn norm_inf(&self) -> Interval {
+        self.iter()
+            .fold(Interval::zero(), |max, &x| max.max(x.abs()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::{projection, Matrix};
+    use rand::prelude::*;
+
+    const NB_TESTCASE: usize = 100;
+
+    fn points<const M: usize, const N: usize>(data: [[f64; N]; M]) -> Matrix<Interval, M, N> {
+        Matrix::new(data.map(|row| row.map(Interval::point)))
+    }
+
+    #[test]
+    fn interval_arithmetic() {
+        let a = Interval::new(1.0, 2.0);
+        let b = Interval::new(-3.0, 0.5);
+
+        assert!((a + b).contains(-2.0) && (a + b).contains(2.5));
+        assert!((a * b).contains(-6.0) && (a * b).contains(1.0));
+        assert_eq!(a / b, Interval::ENTIRE);
+        assert!((Interval::one() / Interval::point(3.0)).contains(1.0 / 3.0));
+        assert!(Interval::point(0.1) + Interval::point(0.2) > Interval::point(0.2));
+        assert_eq!(b.abs(), Interval::new(0.0, 3.0));
+        assert_eq!(format!("{:.1}", a), "[1.0, 2.0]");
+    }
+
+    #[test]
+    fn interval_elementary() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..NB_TESTCASE {
+            let lo: f64 = rng.gen_range(-4.0..4.0);
+            let hi = lo + rng.gen_range(0.0..2.0);
+            let x = Interval::new(lo, hi);
+            let t = rng.gen_range(lo..=hi);
+
+            assert!(x.sin().contains(t.sin()));
+            assert!(x.cos().contains(t.cos()));
+            assert!(x.tan().contains(t.tan()));
+            assert!(x.exp().contains(t.exp()));
+            assert!(x.abs().sqrt().contains(t.abs().sqrt()));
+        }
+    }
+
+    #[test]
+    fn interval_dot_norm() {
+        // Plain floating point cancels the 1 away and returns 0.
+        let u = Vector::new([1e16, 1.0, -1e16].map(Interval::point));
+        let v = Vector::new([1.0, 1.0, 1.0].map(Interval::point));
+        assert!(u.dot(&v).contains(1.0));
+
+        let w = Vector::new([3.0, -4.0].map(Interval::point));
+        assert!(w.norm().contains(5.0));
+        assert!(w.norm_1().contains(7.0));
+        assert!(w.norm_inf().contains(4.0));
+    }
+
+    #[test]
+    fn interval_matrix() {
+        let u = points([[8.0, 5.0, -2.0], [4.0, 7.0, 20.0], [7.0, 6.0, 1.0]]);
+        assert!(u.determinant().contains(-174.0));
+
+        let product = u.mul_mat(&u);
+        assert!(product[1][2].contains(4.0 * -2.0 + 7.0 * 20.0 + 20.0 * 1.0));
+
+        let inverse = u.inverse().unwrap();
+        assert!(inverse[0][0].contains(113.0 / 174.0));
+    }
+
+    #[test]
+    fn interval_possibly_singular() {
+        let u = Matrix::new([
+            [Interval::point(1.0), Interval::point(1.0)],
+            [Interval::point(1.0), Interval::new(0.5, 1.5)],
+        ]);
+        assert_eq!(u.inverse(), Err("Matrix is possibly singular"));
+        assert_eq!(
+            points([[0.0, 1.0], [0.0, 2.0]]).inverse(),
+            Err("Matrix is singular")
+        );
+
+        // The point zero ties with [-1, 1] on mignitude, but must not be the
+        // pivot: det = -[-1, 1] is not zero.
+        let x = Interval::new(-1.0, 1.0);
+        let u = Matrix::new([[Interval::zero(), Interval::one()], [x, Interval::zero()]]);
+        let dense = crate::DMatrix::from(u);
+        for determinant in [
+            u.determinant(),
+            u.lu().determinant(),
+            dense.determinant(),
+            crate::Banded::from_dense(&dense, 1, 1)
+                .unwrap()
+                .determinant(),
+        ] {
+            for value in [-1.0, 0.0, 1.0] {
+                assert!(determinant.contains(value));
+            }
+        }
+        assert_eq!(u.inverse(), Err("Matrix is possibly singular"));
+        assert_eq!(u.rank(), 2);
+    }
+
+    #[test]
+    fn interval_projection() {
+        let (fov, ratio, near, far) = (1.2, 16.0 / 9.0, 0.1, 100.0);
+        let enclosure = projection(
+            Interval::point(fov),
+            Interval::point(ratio),
+            Interval::point(near),
+            Interval::point(far),
+        );
+        let expected = projection(fov, ratio, near, far);
+
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!(enclosure[i][j].contains(expected[i][j]));
+            }
+        }
+    }
+}
@@ -0,0 +1,526 @@
+use crate::banded::{Banded, Tridiagonal};
+use crate::dmatrix::DMatrix;
+use crate::dvector::DVector;
+use crate::field::{Real, Ring};
+use crate::matrix::Matrix;
+use crate::qr::givens;
+use crate::sparse::{CscMatrix, CsrMatrix};
+
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+/// A square operator known only through `x ↦ A·x`, which is all the Krylov
+/// solvers below need.
+pub trait LinearOperator<K> {
+    fn dim(&self) -> usize;
+
+    fn apply(&self, x: &[K]) -> DVector<K>;
+}
+
+impl<K: Ring, const N: usize> LinearOperator<K> for Matrix<K, N, N> {
+    fn dim(&self) -> usize {
+        N
+    }
+
+    fn apply(&self, x: &[K]) -> DVector<K> {
+        DVector::new(self.mul_vec(x).to_vec())
+    }
+}
+
+impl<K: Ring> LinearOperator<K> for DMatrix<K> {
+    fn dim(&self) -> usize {
+        assert_eq!(self.rows(), self.cols(), "Matrix must be square");
+        self.rows()
+    }
+
+    fn apply(&self, x: &[K]) -> DVector<K> {
+        self.mul_vec(x)
+    }
+}
+
+macro_rules! impl_linear_operator {
+    ($($t:ident),*) => {
+        $(
+            impl<K: Ring> LinearOperator<K> for $t<K> {
+                fn dim(&self) -> usize {
+                    assert_eq!(self.rows(), self.cols(), "Matrix must be square");
+                    self.rows()
+                }
+
+                fn apply(&self, x: &[K]) -> DVector<K> {
+                    self.mul_vec(x)
+                }
+            }
+        )*
+    };
+}
+
+impl_linear_operator!(CsrMatrix, CscMatrix);
+
+impl<K: Ring> LinearOperator<K> for Tridiagonal<K> {
+    fn dim(&self) -> usize {
+        Tridiagonal::dim(self)
+    }
+
+    fn apply(&self, x: &[K]) -> DVector<K> {
+        self.mul_vec(x)
+    }
+}
+
+impl<K: Ring> LinearOperator<K> for Banded<K> {
+    fn dim(&self) -> usize {
+        Banded::dim(self)
+    }
+
+    fn apply(&self, x: &[K]) -> DVector<K> {
+        self.mul_vec(x)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KrylovError {
+    /// A division by zero at this iteration stopped the recurrence, e.g. a
+    /// matrix that is not positive definite for `conjugate_gradient`.
+    Breakdown(usize),
+    /// The residual was still above the tolerance after this many iterations.
+    NoConvergence(usize),
+}
+
+impl Display for KrylovError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            KrylovError::Breakdown(iteration) => {
+                write!(f, "Krylov solver broke down at iteration {iteration}")
+            }
+            KrylovError::NoConvergence(iterations) => {
+                write!(
+                    f,
+                    "Krylov solver did not converge in {iterations} iterations"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for KrylovError {}
+
+/// Solution of a Krylov solver with its convergence history.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Convergence<K> {
+    solution: DVector<K>,
+    residual_history: Vec<K>,
+}
+
+impl<K: Real> Convergence<K> {
+    pub fn solution(&self) -> &DVector<K> {
+        &self.solution
+    }
+
+    pub fn iterations(&self) -> usize {
+        self.residual_history.len() - 1
+    }
+
+    /// `‖b - A·x‖₂` before the first iteration and after each one. GMRES
+    /// records the residual estimate of its least-squares problem.
+    pub fn residual_history(&self) -> &[K] {
+        &self.residual_history
+    }
+
+    pub fn residual_norm(&self) -> K {
+        self.residual_history[self.residual_history.len() - 1]
+    }
+}
+
+fn norm<K: Real>(v: &DVector<K>) -> K {
+    v.dot(v).sqrt()
+}
+
+/// `y += a·x`
+fn axpy<K: Real>(y: &mut DVector<K>, a: K, x: &DVector<K>) {
+    y.iter_mut().zip(x.iter()).for_each(|(y, &x)| *y += a * x);
+}
+
+/// Checks the dimensions and returns the absolute stopping threshold
+/// `tolerance·‖b‖`.
+fn threshold<K: Real, A: LinearOperator<K> + ?Sized>(a: &A, b: &DVector<K>, tolerance: K) -> K {
+    assert_eq!(a.dim(), b.dim(), "Vector dimension must match the operator");
+    tolerance * norm(b)
+}
+
+/// Conjugate gradient, for symmetric positive definite `A`. Stops once
+/// `‖b - A·x‖ ≤ tolerance·‖b‖`, starting from `x = 0`.
+pub fn conjugate_gradient<K: Real, A: LinearOperator<K> + ?Sized>(
+    a: &A,
+    b: &DVector<K>,
+    tolerance: K,
+    max_iterations: usize,
+) -> Result<Convergence<K>, KrylovError> {
+    let threshold = threshold(a, b, tolerance);
+    let mut x = DVector::zeros(b.dim());
+    let mut r = b.clone();
+    let mut p = r.clone();
+    let mut rr = r.dot(&r);
+    let mut residual_history = vec![rr.sqrt()];
+
+    for iteration in 1..=max_iterations {
+        if rr.sqrt() <= threshold {
+            break;
+        }
+        let ap = a.apply(&p);
+        let pap = p.dot(&ap);
+        if pap.partial_cmp(&K::zero()) != Some(Ordering::Greater) {
+            return Err(KrylovError::Breakdown(iteration));
+        }
+        let alpha = rr / pap;
+        axpy(&mut x, alpha, &p);
+        axpy(&mut r, -alpha, &ap);
+
+        let next = r.dot(&r);
+        residual_history.push(next.sqrt());
+        let beta = next / rr;
+        rr = next;
+        p.scl(beta);
+        p.add(&r);
+    }
+
+    if rr.sqrt() <= threshold {
+        Ok(Convergence {
+            solution: x,
+            residual_history,
+        })
+    } else {
+        Err(KrylovError::NoConvergence(max_iterations))
+    }
+}
+
+/// Stabilized biconjugate gradient (van der Vorst), for general
+/// non-singular `A`. Same stopping rule as `conjugate_gradient`.
+pub fn bicgstab<K: Real, A: LinearOperator<K> + ?Sized>(
+    a: &A,
+    b: &DVector<K>,
+    tolerance: K,
+    max_iterations: usize,
+) -> Result<Convergence<K>, KrylovError> {
+    let threshold = threshold(a, b, tolerance);
+    let n = b.dim();
+    let mut x = DVector::zeros(n);
+    let mut r = b.clone();
+    let shadow = r.clone();
+    let mut p = DVector::zeros(n);
+    let mut v = DVector::zeros(n);
+    let (mut rho, mut alpha, mut omega) = (K::one(), K::one(), K::one());
+    let mut residual_history = vec![norm(&r)];
+
+    for iteration in 1..=max_iterations {
+        if residual_history[iteration - 1] <= threshold {
+            break;
+        }
+        let next_rho = shadow.dot(&r);
+        if next_rho.may_be_zero() || omega.may_be_zero() {
+            return Err(KrylovError::Breakdown(iteration));
+        }
+        let beta = (next_rho / rho) * (alpha / omega);
+        rho = next_rho;
+        axpy(&mut p, -omega, &v);
+        p.scl(beta);
+        p.add(&r);
+
+        v = a.apply(&p);
+        let denominator = shadow.dot(&v);
+        if denominator.may_be_zero() {
+            return Err(KrylovError::Breakdown(iteration));
+        }
+        alpha = rho / denominator;
+        axpy(&mut x, alpha, &p);
+        axpy(&mut r, -alpha, &v);
+        if norm(&r) <= threshold {
+            residual_history.push(norm(&r));
+            break;
+        }
+
+        let t = a.apply(&r);
+        let tt = t.dot(&t);
+        if tt.may_be_zero() {
+            return Err(KrylovError::Breakdown(iteration));
+        }
+        omega = t.dot(&r) / tt;
+        axpy(&mut x, omega, &r);
+        axpy(&mut r, -omega, &t);
+        residual_history.push(norm(&r));
+    }
+
+    if residual_history[residual_history.len() - 1] <= threshold {
+        Ok(Convergence {
+            solution: x,
+            residual_history,
+        })
+    } else {
+        Err(KrylovError::NoConvergence(max_iterations))
+    }
+}
+
+/// Restarted GMRES(`restart`), for general non-singular `A`: minimizes the
+/// residual over a Krylov subspace built by Arnoldi, restarting from the
+/// current iterate once it reaches dimension `restart`. `max_iterations`
+/// counts matrix-vector products across restarts.
+pub fn gmres<K: Real, A: LinearOperator<K> + ?Sized>(
+    a: &A,
+    b: &DVector<K>,
+    restart: usize,
+    tolerance: K,
+    max_iterations: usize,
+) -> Result<Convergence<K>, KrylovError> {
+    assert!(restart > 0, "GMRES needs a restart length of at least one");
+    let threshold = threshold(a, b, tolerance);
+    let mut x = DVector::zeros(b.dim());
+    let mut residual_history = vec![norm(b)];
+    let mut iteration = 0;
+
+    loop {
+        let mut r = b.clone();
+        r.sub(&a.apply(&x));
+        let beta = norm(&r);
+        if beta <= threshold {
+            return Ok(Convergence {
+                solution: x,
+                residual_history,
+            });
+        }
+        if iteration == max_iterations {
+            return Err(KrylovError::NoConvergence(max_iterations));
+        }
+
+        r.scl(K::one() / beta);
+        let mut basis = vec![r];
+        // Column `j` of the Hessenberg matrix, already rotated to triangular.
+        let mut h: Vec<Vec<K>> = Vec::new();
+        let mut rotations = Vec::new();
+        let mut g = vec![beta];
+
+        while h.len() < restart && iteration < max_iterations {
+            iteration += 1;
+            let j = h.len();
+            let mut w = a.apply(&basis[j]);
+            let mut column = Vec::with_capacity(j + 2);
+            for v in &basis {
+                let coefficient = w.dot(v);
+                axpy(&mut w, -coefficient, v);
+                column.push(coefficient);
+            }
+            let next_norm = norm(&w);
+            column.push(next_norm);
+
+            for (i, &(c, s)) in rotations.iter().enumerate() {
+                let (top, bottom) = (column[i], column[i + 1]);
+                column[i] = c * top + s * bottom;
+                column[i + 1] = c * bottom - s * top;
+            }
+            let (c, s) = givens(column[j], column[j + 1]);
+            column[j] = c * column[j] + s * column[j + 1];
+            // `A` maps the subspace into a smaller one: the projected
+            // problem is singular and the residual estimate meaningless.
+            if column[j].may_be_zero() {
+                return Err(KrylovError::Breakdown(iteration));
+            }
+            column.pop();
+            rotations.push((c, s));
+            g.push(-s * g[j]);
+            g[j] = c * g[j];
+            h.push(column);
+
+            let estimate = g[j + 1].abs();
+            residual_history.push(estimate);
+            if estimate <= threshold {
+                break;
+            }
+            if next_norm.may_be_zero() {
+                return Err(KrylovError::Breakdown(iteration));
+            }
+            w.scl(K::one() / next_norm);
+            basis.push(w);
+        }
+
+        let k = h.len();
+        let mut y = vec![K::zero(); k];
+        for i in (0..k).rev() {
+            let value = ((i + 1)..k).fold(g[i], |acc, j| acc - h[j][i] * y[j]);
+            y[i] = value / h[i][i];
+        }
+        for (v, &coefficient) in basis.iter().zip(&y) {
+            axpy(&mut x, coefficient, v);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sparse::CooMatrix;
+    use crate::vector::Vector;
+    use approx::assert_abs_diff_eq;
+    use rand::prelude::*;
+
+    const NB_TESTCASE_MATRICES: usize = 100;
+    const THRESHOLD: f64 = 1e-8;
+    const TOLERANCE: f64 = 1e-12;
+
+    fn generate_random_matrix<const M: usize, const N: usize>() -> Matrix<f64, M, N> {
+        let mut rng = rand::thread_rng();
+        let mut data = [[0.0; N]; M];
+        for row in &mut data {
+            for elem in row {
+                *elem = rng.gen_range(-1.0..1.0);
+            }
+        }
+        Matrix::new(data)
+    }
+
+    fn random_rhs<const N: usize>() -> DVector<f64> {
+        DVector::new(generate_random_matrix::<1, N>()[0].to_vec())
+    }
+
+    fn assert_solves<A: LinearOperator<f64> + ?Sized>(
+        a: &A,
+        b: &DVector<f64>,
+        result: &Convergence<f64>,
+    ) {
+        let residual = b.clone() - a.apply(result.solution());
+        for value in residual.iter() {
+            assert_abs_diff_eq!(*value, 0., epsilon = THRESHOLD);
+        }
+        assert_eq!(result.residual_history().len(), result.iterations() + 1);
+        assert_abs_diff_eq!(result.residual_history()[0], norm(b), epsilon = THRESHOLD);
+    }
+
+    /// The 1D Poisson matrix `tridiag(-1, 2, -1)`.
+    fn poisson(n: usize) -> CsrMatrix<f64> {
+        let mut coo = CooMatrix::new(n, n);
+        for i in 0..n {
+            coo.push(i, i, 2.);
+            if i > 0 {
+                coo.push(i, i - 1, -1.);
+                coo.push(i - 1, i, -1.);
+            }
+        }
+        coo.to_csr()
+    }
+
+    #[test]
+    fn krylov_conjugate_gradient() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let m = generate_random_matrix::<6, 6>();
+            let a = m.transpose().mul_mat(&m) + Matrix::identity();
+            let b = random_rhs::<6>();
+            let result = conjugate_gradient(&a, &b, TOLERANCE, 100).unwrap();
+            assert_solves(&a, &b, &result);
+            let expected = a.lu().solve(&Vector::new(std::array::from_fn(|i| b[i])));
+            let expected = expected.unwrap();
+            for i in 0..6 {
+                assert_abs_diff_eq!(result.solution()[i], expected[i], epsilon = 1e-6);
+            }
+        }
+
+        // In exact arithmetic CG finishes in at most `n` steps.
+        let a = poisson(50);
+        let b = DVector::new(vec![1.; 50]);
+        let result = conjugate_gradient(&a, &b, TOLERANCE, 60).unwrap();
+        assert_solves(&a, &b, &result);
+        assert!(result.iterations() <= 50);
+
+        let indefinite = Matrix::new([[0., 1.], [1., 0.]]);
+        assert_eq!(
+            conjugate_gradient(&indefinite, &DVector::new(vec![1., 0.]), TOLERANCE, 10),
+            Err(KrylovError::Breakdown(1))
+        );
+    }
+
+    fn diagonally_dominant<const N: usize>() -> Matrix<f64, N, N> {
+        let mut a = generate_random_matrix::<N, N>();
+        for i in 0..N {
+            a[i][i] += N as f64;
+        }
+        a
+    }
+
+    #[test]
+    fn krylov_bicgstab() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let a = diagonally_dominant::<6>();
+            let b = random_rhs::<6>();
+            assert_solves(&a, &b, &bicgstab(&a, &b, TOLERANCE, 100).unwrap());
+
+            let dense = DMatrix::new(6, 6, a.iter().flatten().copied().collect());
+            assert_solves(&dense, &b, &bicgstab(&dense, &b, TOLERANCE, 100).unwrap());
+        }
+
+        let a = poisson(20);
+        let b = DVector::new(vec![1.; 20]);
+        assert_solves(&a, &b, &bicgstab(&a, &b, TOLERANCE, 200).unwrap());
+
+        let swap = Matrix::new([[0., 1.], [1., 0.]]);
+        assert_eq!(
+            bicgstab(&swap, &DVector::new(vec![1., 0.]), TOLERANCE, 10),
+            Err(KrylovError::Breakdown(1))
+        );
+    }
+
+    #[test]
+    fn krylov_gmres() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let a = generate_random_matrix::<6, 6>();
+            let b = random_rhs::<6>();
+            if a.condition_number() > 1e3 {
+                continue;
+            }
+            // Full GMRES converges in at most `n` steps.
+            let result = gmres(&a, &b, 6, TOLERANCE, 6).unwrap();
+            assert_solves(&a, &b, &result);
+            for pair in result.residual_history().windows(2) {
+                assert!(pair[1] <= pair[0] + THRESHOLD);
+            }
+
+            let a = diagonally_dominant::<6>();
+            let csc = CscMatrix::from(a);
+            assert_solves(&csc, &b, &gmres(&csc, &b, 3, TOLERANCE, 100).unwrap());
+        }
+
+        let a = Tridiagonal::new(vec![-1.; 29], vec![2.; 30], vec![-0.5; 29]);
+        let b = DVector::new(vec![1.; 30]);
+        assert_solves(&a, &b, &gmres(&a, &b, 30, TOLERANCE, 30).unwrap());
+
+        let nilpotent = Matrix::new([[0., 1.], [0., 0.]]);
+        assert_eq!(
+            gmres(&nilpotent, &DVector::new(vec![1., 0.]), 2, TOLERANCE, 10),
+            Err(KrylovError::Breakdown(1))
+        );
+    }
+
+    #[test]
+    fn krylov_no_convergence() {
+        let a = poisson(30);
+        let b = DVector::new(vec![1.; 30]);
+        assert_eq!(
+            conjugate_gradient(&a, &b, TOLERANCE, 3),
+            Err(KrylovError::NoConvergence(3))
+        );
+        assert_eq!(
+            bicgstab(&a, &b, TOLERANCE, 3),
+            Err(KrylovError::NoConvergence(3))
+        );
+        assert_eq!(
+            gmres(&a, &b, 2, TOLERANCE, 3),
+            Err(KrylovError::NoConvergence(3))
+        );
+
+        let zero = DVector::zeros(30);
+        for result in [
+            conjugate_gradient(&a, &zero, TOLERANCE, 0),
+            bicgstab(&a, &zero, TOLERANCE, 0),
+            gmres(&a, &zero, 5, TOLERANCE, 0),
+        ] {
+            let result = result.unwrap();
+            assert_eq!(result.iterations(), 0);
+            assert_eq!(result.solution(), &zero);
+        }
+    }
+}
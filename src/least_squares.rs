@@ -0,0 +1,293 @@
+use crate::field::{Real, RealField};
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LeastSquaresMethod {
+    /// Column-pivoted QR: backward stable, and gives the basic solution
+    /// (free variables set to zero) when the matrix is rank deficient.
+    #[default]
+    QR,
+    /// Cholesky on `Aᵀ·A`: fastest, but squares the condition number and
+    /// needs full column rank.
+    NormalEquations,
+    /// Pseudo-inverse: the minimum-norm solution, also when rank deficient.
+    SVD,
+}
+
+/// Minimizer `x` of `‖A·x - b‖₂`, with its residual `b - A·x`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeastSquares<K, const M: usize, const N: usize> {
+    solution: Vector<K, N>,
+    residual: Vector<K, M>,
+    rank: usize,
+}
+
+impl<K: Real, const M: usize, const N: usize> LeastSquares<K, M, N> {
+    pub fn new(
+        matrix: &Matrix<K, M, N>,
+        rhs: &Vector<K, M>,
+        method: LeastSquaresMethod,
+    ) -> Result<Self, &'static str> {
+        let (solution, rank) = match method {
+            LeastSquaresMethod::QR => by_qr(matrix, rhs),
+            LeastSquaresMethod::NormalEquations => by_normal_equations(matrix, rhs)?,
+            LeastSquaresMethod::SVD => {
+                let svd = matrix.svd();
+                let cutoff = svd.default_cutoff();
+                (svd.pseudo_inverse(cutoff).mul_vec(rhs), svd.rank(cutoff))
+            }
+        };
+        Ok(LeastSquares {
+            solution,
+            residual: *rhs - matrix.mul_vec(&solution),
+            rank,
+        })
+    }
+
+    pub fn solution(&self) -> &Vector<K, N> {
+        &self.solution
+    }
+
+    /// `b - A·x`, unweighted even for `weighted_least_squares`.
+    pub fn residual(&self) -> &Vector<K, M> {
+        &self.residual
+    }
+
+    /// Numerical rank of `A` as seen by the chosen method.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+}
+
+impl<K: Real + RealField, const M: usize, const N: usize> LeastSquares<K, M, N> {
+    pub fn residual_norm(&self) -> f64 {
+        self.residual.norm()
+    }
+}
+
+fn by_qr<K: Real, const M: usize, const N: usize>(
+    matrix: &Matrix<K, M, N>,
+    rhs: &Vector<K, M>,
+) -> (Vector<K, N>, usize) {
+    let qr = matrix.qr_pivoted();
+    let r = qr.r();
+    let tolerance = if M.min(N) == 0 {
+        K::zero()
+    } else {
+        K::from_f64(M.max(N) as f64 * f64::EPSILON) * r[0][0].abs()
+    };
+    let rank = qr.rank(tolerance);
+
+    let c = qr.apply_qt(rhs);
+    let mut y = [K::zero(); N];
+    for i in (0..rank).rev() {
+        let value = ((i + 1)..rank).fold(c[i], |acc, j| acc - r[i][j] * y[j]);
+        y[i] = value / r[i][i];
+    }
+    let mut solution = Vector::new([K::zero(); N]);
+    for (j, &col) in qr.permutation().iter().enumerate() {
+        solution[col] = y[j];
+    }
+    (solution, rank)
+}
+
+fn by_normal_equations<K: Real, const M: usize, const N: usize>(
+    matrix: &Matrix<K, M, N>,
+    rhs: &Vector<K, M>,
+) -> Result<(Vector<K, N>, usize), &'static str> {
+    let transpose = matrix.transpose();
+    let gram = transpose.mul_mat(matrix);
+    let cholesky = gram
+        .cholesky()
+        .map_err(|_| "Matrix does not have full column rank")?;
+    Ok((cholesky.solve(&transpose.mul_vec(rhs)), N))
+}
+
+impl<K: Real, const M: usize, const N: usize> Matrix<K, M, N> {
+    /// Least-squares solution of `A·x = b` by pivoted QR.
+    pub fn least_squares(&self, rhs: &Vector<K, M>) -> LeastSquares<K, M, N> {
+        LeastSquares::new(self, rhs, LeastSquaresMethod::QR)
+            .expect("QR least squares does not fail")
+    }
+
+    pub fn least_squares_with(
+        &self,
+        rhs: &Vector<K, M>,
+        method: LeastSquaresMethod,
+    ) -> Result<LeastSquares<K, M, N>, &'static str> {
+        LeastSquares::new(self, rhs, method)
+    }
+
+    /// Minimizes `Σ wᵢ·(A·x - b)ᵢ²` by scaling row `i` of `A` and `b` by
+    /// `√wᵢ`. A zero weight drops the equation.
+    pub fn weighted_least_squares(
+        &self,
+        rhs: &Vector<K, M>,
+        weights: &Vector<K, M>,
+        method: LeastSquaresMethod,
+    ) -> Result<LeastSquares<K, M, N>, &'static str> {
+        if weights.iter().any(|&w| w < K::zero()) {
+            return Err("Weights must be non-negative");
+        }
+        let mut scaled = *self;
+        let mut scaled_rhs = *rhs;
+        for (i, row) in scaled.iter_mut().enumerate() {
+            let factor = weights[i].sqrt();
+            row.iter_mut().for_each(|x| *x *= factor);
+            scaled_rhs[i] *= factor;
+        }
+
+        let mut result = LeastSquares::new(&scaled, &scaled_rhs, method)?;
+        result.residual = *rhs - self.mul_vec(&result.solution);
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use nalgebra::{DMatrix, DVector};
+    use rand::prelude::*;
+
+    const NB_TESTCASE_MATRICES: usize = 100;
+    const THRESHOLD: f64 = 1e-8;
+
+    fn generate_random_matrix<const M: usize, const N: usize>() -> Matrix<f64, M, N> {
+        let mut rng = rand::thread_rng();
+        let mut data = [[0.0; N]; M];
+        for row in &mut data {
+            for elem in row {
+                *elem = rng.gen_range(-1.0..1.0);
+            }
+        }
+        Matrix::new(data)
+    }
+
+    const METHODS: [LeastSquaresMethod; 3] = [
+        LeastSquaresMethod::QR,
+        LeastSquaresMethod::NormalEquations,
+        LeastSquaresMethod::SVD,
+    ];
+
+    #[test]
+    fn least_squares_full_rank() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let a = generate_random_matrix::<6, 3>();
+            let b = Vector::new(generate_random_matrix::<1, 6>()[0]);
+            if a.condition_number() > 1e3 {
+                continue;
+            }
+            let expected = DMatrix::from_fn(6, 3, |i, j| a[i][j])
+                .svd(true, true)
+                .solve(&DVector::from_fn(6, |i, _| b[i]), 1e-12)
+                .unwrap();
+
+            for method in METHODS {
+                let result = a.least_squares_with(&b, method).unwrap();
+                assert_eq!(result.rank(), 3);
+                for i in 0..3 {
+                    assert_abs_diff_eq!(result.solution()[i], expected[i], epsilon = 1e-6);
+                }
+                // The residual is orthogonal to the column space.
+                let normal = a.transpose().mul_vec(result.residual());
+                for i in 0..3 {
+                    assert_abs_diff_eq!(normal[i], 0., epsilon = 1e-6);
+                }
+                assert_abs_diff_eq!(
+                    result.residual_norm(),
+                    (b - a.mul_vec(result.solution())).norm(),
+                    epsilon = THRESHOLD
+                );
+            }
+            assert_eq!(
+                a.least_squares(&b),
+                a.least_squares_with(&b, LeastSquaresMethod::QR).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn least_squares_line_fit() {
+        // y = 2x + 1 sampled exactly, then with one point moved.
+        let a = Matrix::new([[0., 1.], [1., 1.], [2., 1.], [3., 1.]]);
+        let b = Vector::new([1., 3., 5., 7.]);
+        for method in METHODS {
+            let result = a.least_squares_with(&b, method).unwrap();
+            assert_abs_diff_eq!(result.solution()[0], 2., epsilon = THRESHOLD);
+            assert_abs_diff_eq!(result.solution()[1], 1., epsilon = THRESHOLD);
+            assert_abs_diff_eq!(result.residual_norm(), 0., epsilon = THRESHOLD);
+        }
+
+        let b = Vector::new([1., 3., 5., 11.]);
+        let result = a.least_squares(&b);
+        assert_abs_diff_eq!(result.solution()[0], 3.2, epsilon = THRESHOLD);
+        assert_abs_diff_eq!(result.solution()[1], 0.2, epsilon = THRESHOLD);
+        assert_abs_diff_eq!(result.residual_norm(), 4.8_f64.sqrt(), epsilon = THRESHOLD);
+    }
+
+    #[test]
+    fn least_squares_rank_deficient() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let a = generate_random_matrix::<6, 2>().mul_mat(&generate_random_matrix::<2, 4>());
+            let b = Vector::new(generate_random_matrix::<1, 6>()[0]);
+            let qr = a.least_squares(&b);
+            let svd = a.least_squares_with(&b, LeastSquaresMethod::SVD).unwrap();
+            assert_eq!(qr.rank(), 2);
+            assert_eq!(svd.rank(), 2);
+            assert_eq!(qr.solution().iter().filter(|&&x| x == 0.).count(), 2);
+            assert_abs_diff_eq!(qr.residual_norm(), svd.residual_norm(), epsilon = 1e-6);
+            assert!(svd.solution().norm() <= qr.solution().norm() + 1e-6);
+        }
+
+        let a = Matrix::new([[1., 2.], [2., 4.], [3., 6.]]);
+        assert_eq!(
+            a.least_squares_with(
+                &Vector::new([1., 2., 3.]),
+                LeastSquaresMethod::NormalEquations
+            ),
+            Err("Matrix does not have full column rank")
+        );
+    }
+
+    #[test]
+    fn least_squares_weighted() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let a = generate_random_matrix::<6, 3>();
+            let b = Vector::new(generate_random_matrix::<1, 6>()[0]);
+            let weights = Vector::new(generate_random_matrix::<1, 6>()[0].map(|x| x.abs() + 0.1));
+            if a.condition_number() > 1e3 {
+                continue;
+            }
+            for method in METHODS {
+                let result = a.weighted_least_squares(&b, &weights, method).unwrap();
+                // Weighted normal equations: Aᵀ·W·r = 0.
+                let mut weighted = *result.residual();
+                for i in 0..6 {
+                    weighted[i] *= weights[i];
+                }
+                let normal = a.transpose().mul_vec(&weighted);
+                for i in 0..3 {
+                    assert_abs_diff_eq!(normal[i], 0., epsilon = 1e-6);
+                }
+            }
+        }
+
+        // A zero weight ignores the outlier.
+        let a = Matrix::new([[0., 1.], [1., 1.], [2., 1.], [3., 1.]]);
+        let b = Vector::new([1., 3., 5., 11.]);
+        let weights = Vector::new([1., 1., 1., 0.]);
+        let result = a
+            .weighted_least_squares(&b, &weights, LeastSquaresMethod::QR)
+            .unwrap();
+        assert_abs_diff_eq!(result.solution()[0], 2., epsilon = THRESHOLD);
+        assert_abs_diff_eq!(result.solution()[1], 1., epsilon = THRESHOLD);
+        assert_abs_diff_eq!(result.residual()[3], 4., epsilon = THRESHOLD);
+
+        assert_eq!(
+            a.weighted_least_squares(&b, &Vector::new([1., -1., 1., 1.]), LeastSquaresMethod::QR),
+            Err("Weights must be non-negative")
+        );
+    }
+}
@@ -0,0 +1,57 @@
+pub mod banded;
+pub mod block;
+pub mod cholesky;
+pub mod dmatrix;
+pub mod dual;
+pub mod dvector;
+pub mod eigen;
+pub mod field;
+pub mod functions;
+pub mod gf;
+pub mod interval;
+pub mod krylov;
+pub mod least_squares;
+pub mod lu;
+pub mod matrix;
+pub mod qr;
+pub mod sparse;
+pub mod structured;
+pub mod subspaces;
+pub mod svd;
+pub mod system;
+pub mod vector;
+pub mod view;
+
+pub use banded::{Banded, Tridiagonal};
+pub use block::Blocks;
+pub use cholesky::{Cholesky, CholeskyError, LDL};
+pub use dmatrix::DMatrix;
+pub use dual::{derivative, gradient, jacobian, Dual};
+pub use dvector::DVector;
+pub use eigen::{Eigen, EigenError, SymmetricEigen};
+pub use field::{Field, Real, RealField, Ring};
+pub use gf::Gf;
+pub use interval::Interval;
+pub use krylov::{bicgstab, conjugate_gradient, gmres, Convergence, KrylovError, LinearOperator};
+pub use least_squares::{LeastSquares, LeastSquaresMethod};
+pub use lu::LU;
+pub use matrix::{projection, Matrix};
+pub use qr::QR;
+pub use sparse::{CooMatrix, CscMatrix, CsrMatrix};
+pub use structured::{Diagonal, LowerTriangular, Symmetric, UpperTriangular};
+pub use svd::SVD;
+pub use system::{solve, Solution};
+pub use vector::{angle_cos, cross_product, Vector};
+pub use view::{MatrixView, MatrixViewMut, VectorAccess, VectorView, VectorViewMut};
+
+pub use num_complex::Complex;
+pub use num_rational::{Ratio, Rational64};
+
+use std::ops::{Add, Mul, Sub};
+
+pub fn lerp<T, K>(u: T, v: T, t: K) -> T
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<K, Output = T> + Clone,
+{
+    u.clone() + (v - u) * t
+}
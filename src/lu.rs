@@ -0,0 +1,499 @@
+use crate::field::{Field, Real, RealField};
+use crate::matrix::{division_free_determinant, Matrix};
+use crate::structured::{LowerTriangular, UpperTriangular};
+use crate::vector::Vector;
+
+/// Partially pivoted factorization `P·A = L·U`. `L` (unit diagonal, below the
+/// diagonal) and `U` (on and above it) share one matrix, and `P` is kept as
+/// the row order: row `i` of `P·A` is row `permutation[i]` of `A`.
+///
+/// Singular matrices still factorize; the zero pivot is reported by the
+/// operations that need to divide by it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LU<K, const N: usize> {
+    matrix: Matrix<K, N, N>,
+    factors: Matrix<K, N, N>,
+    permutation: [usize; N],
+    swaps: usize,
+}
+
+/// `P·A = L·U` in place on the row-major matrix `data`, with one row per
+/// entry of `permutation`. Shared by `LU` and the `DMatrix` eliminations;
+/// returns the number of row swaps.
+pub(crate) fn factorize<K: Field>(data: &mut [K], cols: usize, permutation: &mut [usize]) -> usize {
+    let rows = permutation.len();
+    let mut swaps = 0;
+
+    for k in 0..rows.min(cols) {
+        let mut pivot = k;
+        for row in (k + 1)..rows {
+            if data[row * cols + k].is_better_pivot(data[pivot * cols + k]) {
+                pivot = row;
+            }
+        }
+
+        if pivot != k {
+            for j in 0..cols {
+                data.swap(k * cols + j, pivot * cols + j);
+            }
+            permutation.swap(k, pivot);
+            swaps += 1;
+        }
+
+        // Only an exactly zero pivot has nothing to eliminate. One that merely
+        // may be zero still has to clear the column, or `U` is not triangular.
+        let diag = data[k * cols + k];
+        if diag == K::zero() {
+            continue;
+        }
+
+        let (top, bottom) = data.split_at_mut((k + 1) * cols);
+        let pivot_row = &top[k * cols..];
+        for row in bottom.chunks_exact_mut(cols) {
+            if row[k] == K::zero() {
+                continue;
+            }
+            let factor = row[k] / diag;
+            row[k] = factor;
+            for (a, &b) in row.iter_mut().zip(pivot_row).skip(k + 1) {
+                *a -= factor * b;
+            }
+        }
+    }
+
+    swaps
+}
+
+/// Rejects a square factorization with a pivot that is, or may be, zero.
+pub(crate) fn check_pivots<K: Field>(factors: &[K], n: usize) -> Result<(), &'static str> {
+    for i in 0..n {
+        let pivot = factors[i * n + i];
+        if pivot == K::zero() {
+            return Err("Matrix is singular");
+        }
+        if pivot.may_be_zero() {
+            return Err("Matrix is possibly singular");
+        }
+    }
+    Ok(())
+}
+
+/// Forward then back substitution with square factors, on a right-hand side
+/// already in pivot order.
+pub(crate) fn substitute<K: Field>(factors: &[K], n: usize, rhs: &mut [K]) {
+    for i in 0..n {
+        for j in 0..i {
+            let value = rhs[j];
+            rhs[i] -= factors[i * n + j] * value;
+        }
+    }
+    for i in (0..n).rev() {
+        for j in (i + 1)..n {
+            let value = rhs[j];
+            rhs[i] -= factors[i * n + j] * value;
+        }
+        rhs[i] /= factors[i * n + i];
+    }
+}
+
+impl<K: Field, const N: usize> LU<K, N> {
+    pub fn new(matrix: &Matrix<K, N, N>) -> Self {
+        let mut factors = *matrix;
+        let mut permutation = std::array::from_fn(|i| i);
+        let swaps = factorize(factors.as_flattened_mut(), N, &mut permutation);
+
+        LU {
+            matrix: *matrix,
+            factors,
+            permutation,
+            swaps,
+        }
+    }
+
+    pub fn l(&self) -> LowerTriangular<K, N> {
+        let mut result = LowerTriangular::zeros();
+        for i in 0..N {
+            for j in 0..i {
+                result.set(i, j, self.factors[i][j]);
+            }
+            result.set(i, i, K::one());
+        }
+        result
+    }
+
+    pub fn u(&self) -> UpperTriangular<K, N> {
+        let mut result = UpperTriangular::zeros();
+        for i in 0..N {
+            for j in i..N {
+                result.set(i, j, self.factors[i][j]);
+            }
+        }
+        result
+    }
+
+    pub fn p(&self) -> Matrix<K, N, N> {
+        let mut data = [[K::zero(); N]; N];
+        for (row, &col) in data.iter_mut().zip(&self.permutation) {
+            row[col] = K::one();
+        }
+        Matrix::new(data)
+    }
+
+    pub fn permutation(&self) -> &[usize; N] {
+        &self.permutation
+    }
+
+    /// Number of row swaps performed; its parity is the sign of `P`.
+    pub fn swaps(&self) -> usize {
+        self.swaps
+    }
+
+    /// Determinant of `P`.
+    pub fn sign(&self) -> K {
+        if self.swaps.is_multiple_of(2) {
+            K::one()
+        } else {
+            -K::one()
+        }
+    }
+
+    fn check_pivots(&self) -> Result<(), &'static str> {
+        check_pivots(self.factors.as_flattened(), N)
+    }
+
+    pub fn is_singular(&self) -> bool {
+        self.check_pivots().is_err()
+    }
+
+    pub fn solve(&self, rhs: &Vector<K, N>) -> Result<Vector<K, N>, &'static str> {
+        self.check_pivots()?;
+
+        let mut result = Vector::new(self.permutation.map(|i| rhs[i]));
+        substitute(self.factors.as_flattened(), N, &mut result);
+        Ok(result)
+    }
+
+    /// Solves for every column of `rhs` at once.
+    pub fn solve_mat<const P: usize>(
+        &self,
+        rhs: &Matrix<K, N, P>,
+    ) -> Result<Matrix<K, N, P>, &'static str> {
+        self.check_pivots()?;
+
+        let mut result = self.permutation.map(|i| rhs[i]);
+        for i in 0..N {
+            for j in 0..i {
+                let (done, rest) = result.split_at_mut(i);
+                for (a, &b) in rest[0].iter_mut().zip(&done[j]) {
+                    *a -= self.factors[i][j] * b;
+                }
+            }
+        }
+        for i in (0..N).rev() {
+            for j in (i + 1)..N {
+                let (head, done) = result.split_at_mut(j);
+                for (a, &b) in head[i].iter_mut().zip(&done[0]) {
+                    *a -= self.factors[i][j] * b;
+                }
+            }
+            for a in result[i].iter_mut() {
+                *a /= self.factors[i][i];
+            }
+        }
+
+        Ok(Matrix::new(result))
+    }
+
+    /// Solves `Aᴴ·x = rhs` with the same factors: `Aᴴ = Uᴴ·Lᴴ·P`.
+    pub fn solve_adjoint(&self, rhs: &Vector<K, N>) -> Result<Vector<K, N>, &'static str> {
+        self.check_pivots()?;
+
+        let mut work = *rhs;
+        for i in 0..N {
+            for j in 0..i {
+                let value = work[j];
+                work[i] -= self.factors[j][i].conjugate() * value;
+            }
+            work[i] /= self.factors[i][i].conjugate();
+        }
+        for i in (0..N).rev() {
+            for j in (i + 1)..N {
+                let value = work[j];
+                work[i] -= self.factors[j][i].conjugate() * value;
+            }
+        }
+
+        let mut result = Vector::new([K::zero(); N]);
+        for (i, &row) in self.permutation.iter().enumerate() {
+            result[row] = work[i];
+        }
+        Ok(result)
+    }
+
+    /// The diagonal product, unless elimination divided by a pivot that may
+    /// be zero: the factors then no longer describe `A`, and the determinant
+    /// falls back to Berkowitz on `A` as `Matrix::determinant` does.
+    pub fn determinant(&self) -> K {
+        for i in 0..N {
+            let pivot = self.factors[i][i];
+            if pivot == K::zero() {
+                return K::zero();
+            }
+            if pivot.may_be_zero() {
+                return division_free_determinant(self.matrix.as_flattened(), N);
+            }
+        }
+        (0..N).fold(self.sign(), |acc, i| acc * self.factors[i][i])
+    }
+
+    pub fn inverse(&self) -> Result<Matrix<K, N, N>, &'static str> {
+        self.solve_mat(&Matrix::identity())
+    }
+}
+
+impl<K: Field + RealField, const N: usize> LU<K, N> {
+    /// `ln |det A|`, which stays finite where the determinant itself would
+    /// overflow or underflow. Singular matrices give negative infinity.
+    pub fn log_determinant(&self) -> f64 {
+        (0..N).fold(0.0, |acc, i| acc + self.factors[i][i].modulus().ln())
+    }
+}
+
+impl<K: Real + RealField, const N: usize> LU<K, N> {
+    /// Estimates `1 / (‖A‖₁·‖A⁻¹‖₁)` without forming `A⁻¹`, using Hager's
+    /// method as refined by Higham (LAPACK's `gecon`). `‖A‖₁` comes from the
+    /// matrix kept at factorization, which the factors alone no longer carry.
+    pub fn rcond(&self) -> f64 {
+        let norm_1 = self.matrix.norm_1();
+        if N == 0 {
+            return f64::INFINITY;
+        }
+        if self.is_singular() || norm_1 == 0.0 {
+            return 0.0;
+        }
+
+        let n = N as f64;
+        let mut x = Vector::new([K::from_f64(1.0 / n); N]);
+        let mut estimate = 0.0;
+        let mut last = None;
+        for _ in 0..5 {
+            let y = self.solve(&x).unwrap();
+            estimate = y.norm_1();
+
+            let signs = Vector::new(std::array::from_fn(|i| {
+                if y[i] < K::zero() {
+                    -K::one()
+                } else {
+                    K::one()
+                }
+            }));
+            let z = self.solve_adjoint(&signs).unwrap();
+
+            let mut best = 0;
+            for j in 1..N {
+                if z[j].modulus() > z[best].modulus() {
+                    best = j;
+                }
+            }
+            if last == Some(best) || z[best].modulus() <= z.dot(&x).real() {
+                break;
+            }
+            last = Some(best);
+            x = Vector::new([K::zero(); N]);
+            x[best] = K::one();
+        }
+
+        // Alternating test vector guarding against the power method stalling.
+        let alternating = Vector::new(std::array::from_fn(|i| {
+            let magnitude = 1.0 + i as f64 / (n - 1.0).max(1.0);
+            K::from_f64(if i % 2 == 0 { magnitude } else { -magnitude })
+        }));
+        let alternate = 2.0 * self.solve(&alternating).unwrap().norm_1() / (3.0 * n);
+
+        1.0 / (norm_1 * estimate.max(alternate))
+    }
+}
+
+impl<K: Field, const N: usize> Matrix<K, N, N> {
+    pub fn lu(&self) -> LU<K, N> {
+        LU::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual::Dual;
+    use crate::interval::Interval;
+    use approx::assert_abs_diff_eq;
+    use nalgebra::DMatrix;
+    use num_traits::{One, Zero};
+    use rand::prelude::*;
+
+    const NB_TESTCASE_MATRICES: usize = 100;
+    const THRESHOLD: f64 = 1e-8;
+
+    fn generate_random_matrix<const M: usize, const N: usize>() -> Matrix<f64, M, N> {
+        let mut rng = rand::thread_rng();
+        let mut data = [[0.0; N]; M];
+        for row in &mut data {
+            for elem in row {
+                *elem = rng.gen_range(-1.0..1.0);
+            }
+        }
+        Matrix::new(data)
+    }
+
+    fn to_nalgebra<const M: usize, const N: usize>(matrix: &Matrix<f64, M, N>) -> DMatrix<f64> {
+        DMatrix::from_fn(M, N, |i, j| matrix[i][j])
+    }
+
+    fn assert_matrix_eq<const M: usize, const N: usize>(
+        matrix: &Matrix<f64, M, N>,
+        expected: &Matrix<f64, M, N>,
+    ) {
+        for i in 0..M {
+            for j in 0..N {
+                assert_abs_diff_eq!(matrix[i][j], expected[i][j], epsilon = THRESHOLD);
+            }
+        }
+    }
+
+    fn test_lu<const N: usize>() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let a = generate_random_matrix::<N, N>();
+            let lu = a.lu();
+            let nalgebra_a = to_nalgebra(&a);
+
+            let l = Matrix::from(lu.l());
+            let u = Matrix::from(lu.u());
+            assert_matrix_eq(&lu.p().mul_mat(&a), &l.mul_mat(&u));
+            assert_abs_diff_eq!(
+                lu.determinant(),
+                nalgebra_a.determinant(),
+                epsilon = THRESHOLD
+            );
+            assert_abs_diff_eq!(
+                lu.log_determinant(),
+                nalgebra_a.determinant().abs().ln(),
+                epsilon = 1e-6
+            );
+
+            if nalgebra_a.determinant().abs() < 1e-3 {
+                continue;
+            }
+
+            let inverse = lu.inverse().unwrap();
+            let expected = nalgebra_a.clone().try_inverse().unwrap();
+            for i in 0..N {
+                for j in 0..N {
+                    assert_abs_diff_eq!(inverse[i][j], expected[(i, j)], epsilon = 1e-6);
+                }
+            }
+
+            let b = Vector::new(generate_random_matrix::<1, N>()[0]);
+            let x = lu.solve(&b).unwrap();
+            let y = lu.solve_adjoint(&b).unwrap();
+            for i in 0..N {
+                assert_abs_diff_eq!(a.mul_vec(&x)[i], b[i], epsilon = THRESHOLD);
+                assert_abs_diff_eq!(a.transpose().mul_vec(&y)[i], b[i], epsilon = THRESHOLD);
+            }
+
+            let rhs = generate_random_matrix::<N, 3>();
+            assert_matrix_eq(&a.mul_mat(&lu.solve_mat(&rhs).unwrap()), &rhs);
+
+            // The estimate bounds ‖A⁻¹‖₁ from below, so rcond from above.
+            let exact = 1.0 / (a.norm_1() * inverse.norm_1());
+            let estimate = lu.rcond();
+            assert!(estimate >= exact * (1.0 - 1e-9));
+            assert!(estimate <= exact * 3.0 * N as f64);
+        }
+    }
+
+    #[test]
+    fn lu_1() {
+        test_lu::<1>();
+    }
+
+    #[test]
+    fn lu_4() {
+        test_lu::<4>();
+    }
+
+    #[test]
+    fn lu_8() {
+        test_lu::<8>();
+    }
+
+    #[test]
+    fn lu_swap_parity() {
+        // A single row swap flips the sign the diagonal product alone misses.
+        let swap = Matrix::new([[0., 1.], [1., 0.]]);
+        let lu = swap.lu();
+        assert_eq!(lu.swaps(), 1);
+        assert_eq!(lu.permutation(), &[1, 0]);
+        assert_eq!(lu.determinant(), -1.);
+
+        let singular = Matrix::new([[1., 2.], [2., 4.]]);
+        assert_eq!(singular.lu().determinant(), 0.);
+        assert_eq!(singular.lu().rcond(), 0.);
+        assert_eq!(
+            singular.lu().solve(&Vector::new([1., 1.])),
+            Err("Matrix is singular")
+        );
+
+        let diagonal = Matrix::new([[1e-6, 0.], [0., 1.]]);
+        assert_abs_diff_eq!(diagonal.lu().rcond(), 1e-6, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn lu_interval_determinant() {
+        // Both pivot candidates straddle zero, yet det = 2·a - b must still be
+        // enclosed for a in [-1, 1] and b in [-0.5, 0.5].
+        let (a, b) = (Interval::new(-1., 1.), Interval::new(-0.5, 0.5));
+        let matrix = Matrix::new([[a, Interval::point(1.)], [b, Interval::point(2.)]]);
+        let lu = matrix.lu();
+        let determinant = lu.determinant();
+        for (x, y) in [(-1., -0.5), (-1., 0.5), (1., -0.5), (1., 0.5), (0., 0.)] {
+            assert!(determinant.contains(2. * x - y));
+        }
+        assert_eq!(
+            lu.solve(&Vector::new([Interval::point(1.); 2])),
+            Err("Matrix is possibly singular")
+        );
+        assert_eq!(determinant, matrix.determinant());
+    }
+
+    #[test]
+    fn lu_dual_determinant() {
+        // t is divided by while eliminating its column, which only Bareiss
+        // avoided: det = -t, with derivative -1 at t = 0.
+        let t = Dual::variable(0.);
+        let (zero, one) = (Dual::zero(), Dual::one());
+        let matrix = Matrix::new([[t, one, zero], [t, zero, one], [zero, zero, one]]);
+        assert_eq!(matrix.determinant(), -t);
+        assert_eq!(matrix.lu().determinant(), matrix.determinant());
+
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let a = generate_random_matrix::<4, 4>();
+            let dual = Matrix::new(a.map(|row| row.map(Dual::variable)));
+            let (lu, bareiss) = (dual.lu().determinant(), dual.determinant());
+            assert_abs_diff_eq!(lu.re, bareiss.re, epsilon = THRESHOLD);
+            assert_abs_diff_eq!(lu.eps, bareiss.eps, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn gaussian_elimination_matches_lu() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let a = generate_random_matrix::<5, 5>();
+            let mut echelon = a;
+            echelon.gaussian_elimination();
+            assert_eq!(echelon, Matrix::from(a.lu().u()));
+
+            let mut dynamic = crate::DMatrix::from(a);
+            dynamic.gaussian_elimination();
+            assert_eq!(dynamic, crate::DMatrix::from(echelon));
+        }
+    }
+}
@@ -0,0 +1,674 @@
+use crate::field::{Field, Real, RealField, Ring};
+use crate::vector::Vector;
+use crate::view::VectorAccess;
+
+use std::fmt::{Display, Formatter, Result};
+use std::ops::{Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix<K, const M: usize, const N: usize> {
+    data: [[K; N]; M],
+}
+
+impl<K: Ring + Display, const M: usize, const N: usize> Display for Matrix<K, M, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "[")?;
+        for (i, row) in self.iter().enumerate() {
+            if i != 0 {
+                write!(f, "\n ")?;
+            }
+            write!(f, "[")?;
+            for (j, item) in row.iter().enumerate() {
+                if j != 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{:.3}", item)?;
+            }
+            write!(f, "]")?;
+        }
+        writeln!(f, "]")
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> Deref for Matrix<K, M, N> {
+    type Target = [[K; N]; M];
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> DerefMut for Matrix<K, M, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> Add<Matrix<K, M, N>> for Matrix<K, M, N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut data = self;
+        data.operate(&rhs, |a, b| a + b);
+
+        data
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> Add<Vector<K, N>> for Matrix<K, M, N> {
+    type Output = Self;
+
+    fn add(self, rhs: Vector<K, N>) -> Self::Output {
+        let mut result = self;
+        result.operate_vec(&rhs, |a, b| a + b);
+
+        result
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> Add<K> for Matrix<K, M, N> {
+    type Output = Self;
+
+    fn add(self, scalar: K) -> Self::Output {
+        Matrix {
+            data: self.data.map(|row| row.map(|val| val + scalar)),
+        }
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> AddAssign<Matrix<K, M, N>> for Matrix<K, M, N> {
+    fn add_assign(&mut self, rhs: Matrix<K, M, N>) {
+        self.operate(&rhs, |a, b| a + b);
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> AddAssign<Vector<K, N>> for Matrix<K, M, N> {
+    fn add_assign(&mut self, rhs: Vector<K, N>) {
+        self.operate_vec(&rhs, |a, b| a + b);
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> AddAssign<K> for Matrix<K, M, N> {
+    fn add_assign(&mut self, scalar: K) {
+        self.operate_scalar(scalar, |a, b| a + b);
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> Sub<Matrix<K, M, N>> for Matrix<K, M, N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut data = self;
+        data.operate(&rhs, |a, b| a - b);
+
+        data
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> Sub<Vector<K, N>> for Matrix<K, M, N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Vector<K, N>) -> Self::Output {
+        let mut result = self;
+        result.operate_vec(&rhs, |a, b| a - b);
+
+        result
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> Sub<K> for Matrix<K, M, N> {
+    type Output = Self;
+
+    fn sub(self, scalar: K) -> Self::Output {
+        Matrix {
+            data: self.data.map(|row| row.map(|val| val - scalar)),
+        }
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> SubAssign<Matrix<K, M, N>> for Matrix<K, M, N> {
+    fn sub_assign(&mut self, rhs: Matrix<K, M, N>) {
+        self.operate(&rhs, |a, b| a - b);
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> SubAssign<Vector<K, N>> for Matrix<K, M, N> {
+    fn sub_assign(&mut self, rhs: Vector<K, N>) {
+        self.operate_vec(&rhs, |a, b| a - b);
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> SubAssign<K> for Matrix<K, M, N> {
+    fn sub_assign(&mut self, scalar: K) {
+        self.operate_scalar(scalar, |a, b| a - b);
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize, const P: usize> Mul<Matrix<K, N, P>>
+    for Matrix<K, M, N>
+{
+    type Output = Matrix<K, M, P>;
+
+    fn mul(self, rhs: Matrix<K, N, P>) -> Self::Output {
+        self.mul_mat(&rhs)
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> Mul<Vector<K, N>> for Matrix<K, M, N> {
+    type Output = Vector<K, M>;
+
+    fn mul(self, rhs: Vector<K, N>) -> Self::Output {
+        self.mul_vec(&rhs)
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> Mul<K> for Matrix<K, M, N> {
+    type Output = Self;
+
+    fn mul(self, scalar: K) -> Self::Output {
+        Matrix {
+            data: self.data.map(|row| row.map(|val| val * scalar)),
+        }
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> MulAssign<Matrix<K, N, N>> for Matrix<K, M, N> {
+    fn mul_assign(&mut self, rhs: Matrix<K, N, N>) {
+        *self = self.mul_mat(&rhs);
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> MulAssign<K> for Matrix<K, M, N> {
+    fn mul_assign(&mut self, scalar: K) {
+        self.operate_scalar(scalar, |a, b| a * b);
+    }
+}
+
+impl<K: Field, const M: usize, const N: usize> Div<K> for Matrix<K, M, N> {
+    type Output = Self;
+
+    fn div(self, scalar: K) -> Self::Output {
+        Matrix {
+            data: self.data.map(|row| row.map(|val| val / scalar)),
+        }
+    }
+}
+
+impl<K: Field, const M: usize, const N: usize> DivAssign<K> for Matrix<K, M, N> {
+    fn div_assign(&mut self, scalar: K) {
+        self.operate_scalar(scalar, |a, b| a / b);
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> Matrix<K, M, N> {
+    pub fn new(data: [[K; N]; M]) -> Self {
+        Matrix { data }
+    }
+
+    fn operate<F: Fn(K, K) -> K>(&mut self, v: &Matrix<K, M, N>, op: F) {
+        self.iter_mut().zip(&v.data).for_each(|(a_row, b_row)| {
+            a_row
+                .iter_mut()
+                .zip(b_row)
+                .for_each(|(a, b)| *a = op(*a, *b));
+        });
+    }
+
+    fn operate_vec<F: Fn(K, K) -> K>(&mut self, v: &Vector<K, N>, op: F) {
+        self.iter_mut().for_each(|a_row| {
+            a_row.iter_mut().zip(*v).for_each(|(a, b)| *a = op(*a, b));
+        });
+    }
+
+    fn operate_scalar<F: Fn(K, K) -> K>(&mut self, scalar: K, op: F) {
+        self.iter_mut()
+            .for_each(|row| row.iter_mut().for_each(|elt| *elt = op(*elt, scalar)));
+    }
+
+    pub fn add(&mut self, v: &Matrix<K, M, N>) {
+        self.operate(v, |a, b| a + b);
+    }
+
+    pub fn sub(&mut self, v: &Matrix<K, M, N>) {
+        self.operate(v, |a, b| a - b);
+    }
+
+    pub fn scl(&mut self, scalar: K) {
+        self.operate_scalar(scalar, |a, b| a * b);
+    }
+
+    pub fn mul_mat<const P: usize>(&self, rhs: &Matrix<K, N, P>) -> Matrix<K, M, P> {
+        let mut data = [[K::zero(); P]; M];
+        for (row, lhs_row) in data.iter_mut().zip(&self.data) {
+            for (j, elt) in row.iter_mut().enumerate() {
+                *elt = lhs_row
+                    .iter()
+                    .zip(&rhs.data)
+                    .fold(K::zero(), |acc, (&a, rhs_row)| acc + a * rhs_row[j]);
+            }
+        }
+        Matrix { data }
+    }
+
+    pub fn mul_vec<V: VectorAccess<K> + ?Sized>(&self, rhs: &V) -> Vector<K, M> {
+        assert_eq!(N, rhs.dim(), "Vector dimension must match columns");
+        Vector::new(self.data.map(|row| {
+            row.iter()
+                .enumerate()
+                .fold(K::zero(), |acc, (j, &a)| acc + a * rhs.at(j))
+        }))
+    }
+
+    pub fn transpose(&self) -> Matrix<K, N, M> {
+        let mut data = [[K::zero(); M]; N];
+        for (i, row) in self.data.iter().enumerate() {
+            for (j, &elt) in row.iter().enumerate() {
+                data[j][i] = elt;
+            }
+        }
+        Matrix { data }
+    }
+
+    pub fn conjugate_transpose(&self) -> Matrix<K, N, M> {
+        Matrix {
+            data: self.transpose().data.map(|row| row.map(|x| x.conjugate())),
+        }
+    }
+}
+
+impl<K: Field, const M: usize, const N: usize> Matrix<K, M, N> {
+    pub fn inv_scl(&mut self, scalar: K) {
+        self.operate_scalar(scalar, |a, b| a / b);
+    }
+
+    pub fn row_echelon(&self) -> Self {
+        let mut result = *self;
+        let mut permutation: [usize; M] = std::array::from_fn(|i| i);
+        row_echelon(result.as_flattened_mut(), N, &mut permutation);
+        result
+    }
+
+    pub fn rank(&self) -> usize {
+        self.row_echelon()
+            .iter()
+            .filter(|row| row.iter().any(|&x| x != K::zero()))
+            .count()
+    }
+
+    /// Row echelon form by partial pivoting, through the same elimination as
+    /// `LU`.
+    pub fn gaussian_elimination(&mut self) {
+        let mut permutation: [usize; M] = std::array::from_fn(|i| i);
+        crate::lu::factorize(self.as_flattened_mut(), N, &mut permutation);
+        for (i, row) in self.iter_mut().enumerate() {
+            row.iter_mut().take(i).for_each(|x| *x = K::zero());
+        }
+    }
+}
+
+impl<K: Ring, const N: usize> Matrix<K, N, N> {
+    pub fn identity() -> Self {
+        let mut data = [[K::zero(); N]; N];
+        for (i, row) in data.iter_mut().enumerate() {
+            row[i] = K::one();
+        }
+        Matrix { data }
+    }
+
+    pub fn trace(&self) -> K {
+        self.iter()
+            .enumerate()
+            .fold(K::zero(), |sum, (i, row)| sum + row[i])
+    }
+}
+
+fn swap_rows<K>(data: &mut [K], cols: usize, a: usize, b: usize) {
+    if a != b {
+        for j in 0..cols {
+            data.swap(a * cols + j, b * cols + j);
+        }
+    }
+}
+
+/// Reduced row echelon form in place on the row-major matrix `data`, with one
+/// row per entry of `permutation`, which records the row order as in
+/// `lu::factorize`. Shared by `Matrix` and `DMatrix`.
+pub(crate) fn row_echelon<K: Field>(data: &mut [K], cols: usize, permutation: &mut [usize]) {
+    let rows = permutation.len();
+    let mut pivot_row = 0;
+    for pivot_col in 0..cols {
+        if pivot_row >= rows {
+            break;
+        }
+
+        let mut max_row = pivot_row;
+        for row in (pivot_row + 1)..rows {
+            if data[row * cols + pivot_col].is_better_pivot(data[max_row * cols + pivot_col]) {
+                max_row = row;
+            }
+        }
+
+        if data[max_row * cols + pivot_col] == K::zero() {
+            continue;
+        }
+
+        swap_rows(data, cols, pivot_row, max_row);
+        permutation.swap(pivot_row, max_row);
+        let pivot = data[pivot_row * cols + pivot_col];
+        let pivot_values: Vec<K> = data[pivot_row * cols..(pivot_row + 1) * cols]
+            .iter()
+            .map(|&x| x / pivot)
+            .collect();
+
+        for (row, values) in data.chunks_exact_mut(cols).enumerate() {
+            if row == pivot_row {
+                values.copy_from_slice(&pivot_values);
+                continue;
+            }
+            let factor = values[pivot_col];
+            for (a, &b) in values.iter_mut().zip(&pivot_values).skip(pivot_col) {
+                *a -= factor * b;
+            }
+        }
+
+        pivot_row += 1;
+    }
+}
+
+/// Bareiss elimination on the row-major `n × n` matrix in `data`, falling
+/// back to Berkowitz on a pivot that may be zero. Shared by `Matrix` and
+/// `DMatrix`.
+pub(crate) fn bareiss_determinant<K: Ring + Div<Output = K>>(data: &[K], n: usize) -> K {
+    let mut am = data.to_vec();
+    let mut sign = K::one();
+    let mut previous = K::one();
+
+    for k in 0..n {
+        let mut pivot = k;
+        for row in (k + 1)..n {
+            if am[row * n + k].is_better_pivot(am[pivot * n + k]) {
+                pivot = row;
+            }
+        }
+
+        if am[pivot * n + k] == K::zero() {
+            return K::zero();
+        }
+        if am[pivot * n + k].may_be_zero() {
+            return division_free_determinant(data, n);
+        }
+
+        if pivot != k {
+            swap_rows(&mut am, n, k, pivot);
+            sign = -sign;
+        }
+
+        let (top, bottom) = am.split_at_mut((k + 1) * n);
+        let pivot_values = &top[k * n..];
+        for row in bottom.chunks_exact_mut(n) {
+            let factor = row[k];
+            for (a, &b) in row.iter_mut().zip(pivot_values).skip(k + 1) {
+                *a = (*a * pivot_values[k] - factor * b) / previous;
+            }
+        }
+        previous = pivot_values[k];
+    }
+
+    sign * previous
+}
+
+/// Berkowitz's algorithm on the row-major `n × n` matrix in `data`: builds
+/// the characteristic polynomial of each leading submatrix from the previous
+/// one with ring operations only, so nothing is divided by a pivot that may
+/// be zero. `O(n⁴)`, hence only the fallback for `determinant`.
+pub(crate) fn division_free_determinant<K: Ring>(data: &[K], n: usize) -> K {
+    let at = |i: usize, j: usize| data[i * n + j];
+    let mut polynomial = vec![K::one()];
+    for k in 0..n {
+        // First column of the Toeplitz matrix mapping the characteristic
+        // polynomial of the leading k × k block to that of the next one.
+        let mut toeplitz = vec![K::one(), -at(k, k)];
+        let mut column: Vec<K> = (0..k).map(|i| at(i, k)).collect();
+        for _ in 0..k {
+            let value = (0..k).fold(K::zero(), |acc, j| acc + at(k, j) * column[j]);
+            toeplitz.push(-value);
+            column = (0..k)
+                .map(|i| (0..k).fold(K::zero(), |acc, j| acc + at(i, j) * column[j]))
+                .collect();
+        }
+        polynomial = (0..k + 2)
+            .map(|i| (0..=i.min(k)).fold(K::zero(), |acc, j| acc + toeplitz[i - j] * polynomial[j]))
+            .collect();
+    }
+    // The constant term of det(x·I - A) is (-1)ⁿ·det(A).
+    if n.is_multiple_of(2) {
+        polynomial[n]
+    } else {
+        -polynomial[n]
+    }
+}
+
+impl<K: Ring + Div<Output = K>, const N: usize> Matrix<K, N, N> {
+    /// Fraction-free Bareiss elimination: every division is exact, so integer
+    /// matrices never leave the ring.
+    pub fn determinant(&self) -> K {
+        bareiss_determinant(self.as_flattened(), N)
+    }
+}
+
+impl<K: Field, const N: usize> Matrix<K, N, N> {
+    pub fn inverse(&self) -> std::result::Result<Matrix<K, N, N>, &'static str> {
+        self.lu().inverse()
+    }
+}
+
+impl<K: RealField, const M: usize, const N: usize> Matrix<K, M, N> {
+    /// Maximum absolute column sum.
+    pub fn norm_1(&self) -> f64 {
+        (0..N)
+            .map(|j| self.iter().map(|row| row[j].modulus()).sum())
+            .fold(0.0, f64::max)
+    }
+
+    /// Maximum absolute row sum.
+    pub fn norm_inf(&self) -> f64 {
+        self.iter()
+            .map(|row| row.iter().map(|x| x.modulus()).sum())
+            .fold(0.0, f64::max)
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> From<[[K; N]; M]> for Matrix<K, M, N> {
+    fn from(array: [[K; N]; M]) -> Self {
+        Matrix::new(array)
+    }
+}
+
+pub fn projection<K: Real>(fov: K, ratio: K, near: K, far: K) -> Matrix<K, 4, 4> {
+    let two = K::one() + K::one();
+    let scale = K::one() / (fov / two).tan();
+    let zero = K::zero();
+
+    Matrix::from([
+        [scale / ratio, zero, zero, zero],
+        [zero, scale, zero, zero],
+        [zero, zero, (far + near) / (near - far), -K::one()],
+        [zero, zero, (two * far * near) / (near - far), zero],
+    ])
+}
+
+#[cfg(test)]
+mod complex {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use nalgebra::DMatrix;
+    use num_complex::Complex;
+    use rand::prelude::*;
+
+    const NB_TESTCASE_MATRICES: usize = 100;
+    const THRESHOLD: f64 = 1e-8;
+
+    fn generate_random_matrix<const M: usize, const N: usize>() -> Matrix<Complex<f64>, M, N> {
+        let mut rng = rand::thread_rng();
+        let mut data = [[Complex::new(0.0, 0.0); N]; M];
+        for row in &mut data {
+            for elem in row {
+                *elem = Complex::new(rng.gen(), rng.gen());
+            }
+        }
+        Matrix::new(data)
+    }
+
+    fn to_nalgebra<const M: usize, const N: usize>(
+        matrix: &Matrix<Complex<f64>, M, N>,
+    ) -> DMatrix<Complex<f64>> {
+        DMatrix::from_fn(M, N, |i, j| matrix[i][j])
+    }
+
+    fn assert_complex_eq(value: Complex<f64>, expected: Complex<f64>) {
+        assert_abs_diff_eq!(value.re, expected.re, epsilon = THRESHOLD);
+        assert_abs_diff_eq!(value.im, expected.im, epsilon = THRESHOLD);
+    }
+
+    fn test_complex<const N: usize>() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let my_matrix = generate_random_matrix::<N, N>();
+            let nalgebra_matrix = to_nalgebra(&my_matrix);
+
+            assert_complex_eq(my_matrix.determinant(), nalgebra_matrix.determinant());
+
+            let inverse = my_matrix.inverse().unwrap();
+            let nalgebra_inverse = nalgebra_matrix.clone().try_inverse().unwrap();
+            let adjoint = my_matrix.conjugate_transpose();
+            let nalgebra_adjoint = nalgebra_matrix.adjoint();
+            for i in 0..N {
+                for j in 0..N {
+                    assert_complex_eq(inverse[i][j], nalgebra_inverse[(i, j)]);
+                    assert_complex_eq(adjoint[i][j], nalgebra_adjoint[(i, j)]);
+                }
+            }
+
+            let u = Vector::new(my_matrix[0]);
+            let v = Vector::new(my_matrix[N - 1]);
+            let nalgebra_u = nalgebra_matrix.row(0).transpose();
+            let nalgebra_v = nalgebra_matrix.row(N - 1).transpose();
+            assert_complex_eq(u.dot(&v), nalgebra_u.dotc(&nalgebra_v));
+            assert_abs_diff_eq!(u.norm(), nalgebra_u.norm(), epsilon = THRESHOLD);
+        }
+    }
+
+    #[test]
+    fn complex_matrix_2() {
+        test_complex::<2>();
+    }
+
+    #[test]
+    fn complex_matrix_3() {
+        test_complex::<3>();
+    }
+
+    #[test]
+    fn complex_matrix_6() {
+        test_complex::<6>();
+    }
+}
+
+#[cfg(test)]
+mod rational {
+    use super::*;
+    use num_rational::Rational64;
+
+    fn to_rational<const M: usize, const N: usize>(
+        data: [[i64; N]; M],
+    ) -> Matrix<Rational64, M, N> {
+        Matrix::new(data.map(|row| row.map(Rational64::from_integer)))
+    }
+
+    #[test]
+    fn rational_row_echelon() {
+        let r = Rational64::new;
+        let u = Matrix::from([
+            [r(8, 1), r(5, 1), r(-2, 1), r(4, 1), r(28, 1)],
+            [r(4, 1), r(5, 2), r(20, 1), r(4, 1), r(-4, 1)],
+            [r(8, 1), r(5, 1), r(1, 1), r(4, 1), r(17, 1)],
+        ]);
+        let rref = u.row_echelon();
+
+        assert_eq!(rref[0], [r(1, 1), r(5, 8), r(0, 1), r(0, 1), r(-73, 6)]);
+        assert_eq!(rref[1], [r(0, 1), r(0, 1), r(1, 1), r(0, 1), r(-11, 3)]);
+        assert_eq!(rref[2], [r(0, 1), r(0, 1), r(0, 1), r(1, 1), r(59, 2)]);
+        assert_eq!(
+            format!("{}", rref),
+            "[[1, 5/8, 0, 0, -73/6]\n [0, 0, 1, 0, -11/3]\n [0, 0, 0, 1, 59/2]]\n"
+        );
+    }
+
+    #[test]
+    fn rational_rank() {
+        assert_eq!(to_rational([[1, 2, 3], [4, 5, 6], [7, 8, 9]]).rank(), 2);
+        assert_eq!(
+            to_rational([[1, 2, 0, 0], [2, 4, 0, 0], [-1, 2, 1, 1]]).rank(),
+            2
+        );
+        assert_eq!(
+            to_rational([[8, 5, -2], [4, 7, 20], [7, 6, 1], [21, 18, 7]]).rank(),
+            3
+        );
+    }
+
+    #[test]
+    fn rational_inverse() {
+        let u = to_rational([[8, 5, -2], [4, 7, 20], [7, 6, 1]]);
+        let inverse = u.inverse().unwrap();
+
+        assert_eq!(inverse.mul_mat(&u), Matrix::identity());
+        assert_eq!(inverse[0][0], Rational64::new(113, 174));
+        assert!(to_rational([[1, 2, 3], [4, 5, 6], [7, 8, 9]])
+            .inverse()
+            .is_err());
+    }
+}
+
+#[cfg(test)]
+mod integer {
+    use super::*;
+
+    #[test]
+    fn integer_arithmetic() {
+        let u = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+        let v = Matrix::new([[1, 0], [0, 1], [2, -1]]);
+
+        assert_eq!(u.transpose(), Matrix::new([[1, 4], [2, 5], [3, 6]]));
+        assert_eq!(u.mul_mat(&v), Matrix::new([[7, -1], [16, -1]]));
+        assert_eq!(u.mul_vec(&Vector::new([1, 1, 1])), Vector::new([6, 15]));
+        assert_eq!(Matrix::new([[2, -5, 0], [4, 3, 7], [-2, 3, 4]]).trace(), 9);
+    }
+
+    #[test]
+    fn integer_determinant() {
+        assert_eq!(Matrix::new([[1, -1], [-1, 1]]).determinant(), 0);
+        assert_eq!(
+            Matrix::new([[2, 0, 0], [0, 2, 0], [0, 0, 2]]).determinant(),
+            8
+        );
+        assert_eq!(
+            Matrix::new([[8, 5, -2], [4, 7, 20], [7, 6, 1]]).determinant(),
+            -174
+        );
+        assert_eq!(
+            Matrix::new([[8, 5, -2, 4], [4, 2, 2, 4], [7, 6, 1, 9], [21, 18, 7, 1]]).determinant(),
+            1492
+        );
+        assert_eq!(Matrix::new([[0, 1], [1, 0]]).determinant(), -1);
+    }
+
+    #[test]
+    fn integer_division_free_determinant() {
+        let u = Matrix::new([[8, 5, -2, 4], [4, 2, 2, 4], [7, 6, 1, 9], [21, 18, 7, 1]]);
+        assert_eq!(division_free_determinant(u.as_flattened(), 4), 1492);
+        let u = Matrix::new([[8, 5, -2], [4, 7, 20], [7, 6, 1]]);
+        assert_eq!(division_free_determinant(u.as_flattened(), 3), -174);
+        assert_eq!(division_free_determinant(&[5], 1), 5);
+        assert_eq!(division_free_determinant::<i64>(&[], 0), 1);
+    }
+}
@@ -0,0 +1,371 @@
+use crate::field::Real;
+use crate::matrix::Matrix;
+use crate::structured::UpperTriangular;
+use crate::vector::Vector;
+
+/// Householder factorization `A·P = Q·R`, with `P` the identity unless built
+/// by `qr_pivoted`. `R` is kept on and above the diagonal; below it, column
+/// `k` holds the reflector `H_k = I - tau_k·v·vᵀ` whose leading `1` is
+/// implicit. `Q = H_0·H_1·…` is only formed on request.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QR<K, const M: usize, const N: usize> {
+    factors: Matrix<K, M, N>,
+    tau: [K; N],
+    permutation: [usize; N],
+}
+
+/// Rotation `[[c, s], [-s, c]]` sending `(a, b)` to `(r, 0)`.
+pub(crate) fn givens<K: Real>(a: K, b: K) -> (K, K) {
+    if b == K::zero() {
+        return (K::one(), K::zero());
+    }
+    // Scaled like `hypot`, so squaring neither overflows nor underflows.
+    let scale = if a.abs() > b.abs() { a.abs() } else { b.abs() };
+    let (x, y) = (a / scale, b / scale);
+    let r = scale * (x * x + y * y).sqrt();
+    (a / r, b / r)
+}
+
+impl<K: Real, const M: usize, const N: usize> QR<K, M, N> {
+    fn factorize(matrix: &Matrix<K, M, N>, pivoting: bool) -> Self {
+        let mut factors = *matrix;
+        let mut tau = [K::zero(); N];
+        let mut permutation = std::array::from_fn(|j| j);
+
+        for k in 0..M.min(N) {
+            if pivoting {
+                let norm =
+                    |j: usize| (k..M).fold(K::zero(), |acc, i| acc + factors[i][j] * factors[i][j]);
+                let mut pivot = k;
+                let mut best = norm(k);
+                for j in (k + 1)..N {
+                    let candidate = norm(j);
+                    if candidate > best {
+                        pivot = j;
+                        best = candidate;
+                    }
+                }
+                if pivot != k {
+                    for row in factors.iter_mut() {
+                        row.swap(k, pivot);
+                    }
+                    permutation.swap(k, pivot);
+                }
+            }
+
+            // The column is scaled by its largest entry before squaring, as
+            // in `givens`.
+            let scale = (k..M).fold(K::zero(), |acc, i| {
+                let x = factors[i][k].abs();
+                if x > acc {
+                    x
+                } else {
+                    acc
+                }
+            });
+            if scale == K::zero() {
+                continue;
+            }
+            let head = factors[k][k];
+            let tail = ((k + 1)..M).fold(K::zero(), |acc, i| {
+                let x = factors[i][k] / scale;
+                acc + x * x
+            });
+            if tail == K::zero() {
+                continue;
+            }
+
+            let norm = scale * ((head / scale) * (head / scale) + tail).sqrt();
+            let beta = if head < K::zero() { norm } else { -norm };
+            tau[k] = (beta - head) / beta;
+            let scale = head - beta;
+            for row in factors.iter_mut().skip(k + 1) {
+                row[k] /= scale;
+            }
+            factors[k][k] = beta;
+
+            for j in (k + 1)..N {
+                let dot =
+                    ((k + 1)..M).fold(factors[k][j], |acc, i| acc + factors[i][k] * factors[i][j]);
+                let s = tau[k] * dot;
+                factors[k][j] -= s;
+                for i in (k + 1)..M {
+                    let v = factors[i][k];
+                    factors[i][j] -= s * v;
+                }
+            }
+        }
+
+        QR {
+            factors,
+            tau,
+            permutation,
+        }
+    }
+
+    fn reflect(&self, k: usize, column: &mut [K]) {
+        let dot = ((k + 1)..M).fold(column[k], |acc, i| acc + self.factors[i][k] * column[i]);
+        let s = self.tau[k] * dot;
+        column[k] -= s;
+        for (i, x) in column.iter_mut().enumerate().skip(k + 1) {
+            *x -= s * self.factors[i][k];
+        }
+    }
+
+    /// `Qᵀ·b`, applying the reflectors one by one.
+    pub fn apply_qt(&self, b: &Vector<K, M>) -> Vector<K, M> {
+        let mut result = *b;
+        for k in 0..M.min(N) {
+            self.reflect(k, &mut result);
+        }
+        result
+    }
+
+    /// `Q·b`, applying the reflectors in reverse.
+    pub fn apply_q(&self, b: &Vector<K, M>) -> Vector<K, M> {
+        let mut result = *b;
+        for k in (0..M.min(N)).rev() {
+            self.reflect(k, &mut result);
+        }
+        result
+    }
+
+    /// The full `M × M` orthogonal factor.
+    pub fn q(&self) -> Matrix<K, M, M> {
+        let mut columns = Matrix::<K, M, M>::identity();
+        for column in columns.iter_mut() {
+            for k in (0..M.min(N)).rev() {
+                self.reflect(k, column);
+            }
+        }
+        columns.transpose()
+    }
+
+    /// The full `M × N` triangular factor, matching `q`.
+    pub fn r(&self) -> Matrix<K, M, N> {
+        let mut data = [[K::zero(); N]; M];
+        for (i, row) in data.iter_mut().enumerate() {
+            if i < N {
+                row[i..].copy_from_slice(&self.factors[i][i..]);
+            }
+        }
+        Matrix::new(data)
+    }
+
+    /// The first `N` columns of `Q`, for tall matrices.
+    pub fn thin_q(&self) -> Matrix<K, M, N> {
+        const { assert!(M >= N, "thin QR needs at least as many rows as columns") };
+        let mut columns = [[K::zero(); M]; N];
+        for (j, column) in columns.iter_mut().enumerate() {
+            column[j] = K::one();
+            for k in (0..N).rev() {
+                self.reflect(k, column);
+            }
+        }
+        Matrix::new(columns).transpose()
+    }
+
+    /// The top `N × N` block of `R`, for tall matrices.
+    pub fn thin_r(&self) -> UpperTriangular<K, N> {
+        const { assert!(M >= N, "thin QR needs at least as many rows as columns") };
+        let mut result = UpperTriangular::zeros();
+        for i in 0..N {
+            for j in i..N {
+                result.set(i, j, self.factors[i][j]);
+            }
+        }
+        result
+    }
+
+    /// Column `j` of `A·P` is column `permutation[j]` of `A`.
+    pub fn permutation(&self) -> &[usize; N] {
+        &self.permutation
+    }
+
+    pub fn p(&self) -> Matrix<K, N, N> {
+        let mut data = [[K::zero(); N]; N];
+        for (j, &col) in self.permutation.iter().enumerate() {
+            data[col][j] = K::one();
+        }
+        Matrix::new(data)
+    }
+
+    /// Number of diagonal entries of `R` larger than `tolerance` in absolute
+    /// value. Only meaningful for the pivoted factorization, whose diagonal
+    /// is non-increasing.
+    pub fn rank(&self, tolerance: K) -> usize {
+        (0..M.min(N))
+            .take_while(|&i| self.factors[i][i].abs() > tolerance)
+            .count()
+    }
+}
+
+impl<K: Real, const M: usize, const N: usize> Matrix<K, M, N> {
+    pub fn qr(&self) -> QR<K, M, N> {
+        QR::factorize(self, false)
+    }
+
+    /// QR with column pivoting: at each step the remaining column of largest
+    /// norm is moved forward, so `|R|`'s diagonal decreases and reveals rank.
+    pub fn qr_pivoted(&self) -> QR<K, M, N> {
+        QR::factorize(self, true)
+    }
+
+    /// Full `(Q, R)` computed with Givens rotations, zeroing each column
+    /// from the bottom up.
+    pub fn qr_givens(&self) -> (Matrix<K, M, M>, Matrix<K, M, N>) {
+        let mut q = Matrix::<K, M, M>::identity();
+        let mut r = *self;
+        for j in 0..N {
+            for i in ((j + 1)..M).rev() {
+                let (c, s) = givens(r[i - 1][j], r[i][j]);
+                for col in 0..N {
+                    let (a, b) = (r[i - 1][col], r[i][col]);
+                    r[i - 1][col] = c * a + s * b;
+                    r[i][col] = c * b - s * a;
+                }
+                for row in q.iter_mut() {
+                    let (a, b) = (row[i - 1], row[i]);
+                    row[i - 1] = c * a + s * b;
+                    row[i] = c * b - s * a;
+                }
+            }
+        }
+        (q, r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use nalgebra::DMatrix;
+    use rand::prelude::*;
+
+    const NB_TESTCASE_MATRICES: usize = 100;
+    const THRESHOLD: f64 = 1e-8;
+
+    fn generate_random_matrix<const M: usize, const N: usize>() -> Matrix<f64, M, N> {
+        let mut rng = rand::thread_rng();
+        let mut data = [[0.0; N]; M];
+        for row in &mut data {
+            for elem in row {
+                *elem = rng.gen_range(-1.0..1.0);
+            }
+        }
+        Matrix::new(data)
+    }
+
+    fn assert_matrix_eq<const M: usize, const N: usize>(
+        matrix: &Matrix<f64, M, N>,
+        expected: &Matrix<f64, M, N>,
+    ) {
+        for i in 0..M {
+            for j in 0..N {
+                assert_abs_diff_eq!(matrix[i][j], expected[i][j], epsilon = THRESHOLD);
+            }
+        }
+    }
+
+    fn assert_upper_triangular<const M: usize, const N: usize>(matrix: &Matrix<f64, M, N>) {
+        for i in 0..M {
+            for j in 0..i.min(N) {
+                assert_abs_diff_eq!(matrix[i][j], 0., epsilon = THRESHOLD);
+            }
+        }
+    }
+
+    fn test_qr<const M: usize, const N: usize>() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let a = generate_random_matrix::<M, N>();
+            let identity = Matrix::<f64, M, M>::identity();
+
+            let qr = a.qr();
+            let (q, r) = (qr.q(), qr.r());
+            assert_matrix_eq(&q.mul_mat(&r), &a);
+            assert_matrix_eq(&q.transpose().mul_mat(&q), &identity);
+            assert_upper_triangular(&r);
+
+            let nalgebra_r = DMatrix::from_fn(M, N, |i, j| a[i][j]).qr().r();
+            for i in 0..M.min(N) {
+                assert_abs_diff_eq!(r[i][i].abs(), nalgebra_r[(i, i)].abs(), epsilon = THRESHOLD);
+            }
+
+            let b = Vector::new(generate_random_matrix::<1, M>()[0]);
+            let qtb = qr.apply_qt(&b);
+            let expected = q.transpose().mul_vec(&b);
+            for i in 0..M {
+                assert_abs_diff_eq!(qtb[i], expected[i], epsilon = THRESHOLD);
+                assert_abs_diff_eq!(qr.apply_q(&qtb)[i], b[i], epsilon = THRESHOLD);
+            }
+
+            let pivoted = a.qr_pivoted();
+            let r = pivoted.r();
+            assert_matrix_eq(&pivoted.q().mul_mat(&r), &a.mul_mat(&pivoted.p()));
+            for i in 1..M.min(N) {
+                assert!(r[i][i].abs() <= r[i - 1][i - 1].abs() + THRESHOLD);
+            }
+
+            let (q, r) = a.qr_givens();
+            assert_matrix_eq(&q.mul_mat(&r), &a);
+            assert_matrix_eq(&q.transpose().mul_mat(&q), &identity);
+            assert_upper_triangular(&r);
+        }
+    }
+
+    #[test]
+    fn qr_square() {
+        test_qr::<4, 4>();
+    }
+
+    #[test]
+    fn qr_tall() {
+        test_qr::<6, 3>();
+    }
+
+    #[test]
+    fn qr_wide() {
+        test_qr::<3, 5>();
+    }
+
+    #[test]
+    fn qr_thin() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let a = generate_random_matrix::<5, 3>();
+            let qr = a.qr();
+            let q = qr.thin_q();
+            let r = Matrix::from(qr.thin_r());
+            assert_matrix_eq(&q.mul_mat(&r), &a);
+            assert_matrix_eq(&q.transpose().mul_mat(&q), &Matrix::identity());
+        }
+    }
+
+    #[test]
+    fn qr_rank_revealing() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let a = generate_random_matrix::<5, 2>().mul_mat(&generate_random_matrix::<2, 4>());
+            assert_eq!(a.qr_pivoted().rank(1e-10), 2);
+        }
+        assert_eq!(Matrix::<f64, 3, 3>::identity().qr_pivoted().rank(1e-10), 3);
+        assert_eq!(Matrix::new([[0.; 3]; 2]).qr_pivoted().rank(1e-10), 0);
+    }
+
+    #[test]
+    fn qr_extreme_scale() {
+        for factor in [1e200, 1e-200] {
+            let (c, s) = givens(3. * factor, 4. * factor);
+            assert_abs_diff_eq!(c, 0.6, epsilon = THRESHOLD);
+            assert_abs_diff_eq!(s, 0.8, epsilon = THRESHOLD);
+
+            let a = generate_random_matrix::<5, 3>();
+            let mut scaled = a;
+            scaled.scl(factor);
+            let (r, expected) = (scaled.qr().r(), a.qr().r());
+            for i in 0..5 {
+                for j in 0..3 {
+                    assert_abs_diff_eq!(r[i][j] / factor, expected[i][j], epsilon = THRESHOLD);
+                }
+            }
+        }
+    }
+}
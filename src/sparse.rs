@@ -0,0 +1,611 @@
+use crate::dmatrix::DMatrix;
+use crate::dvector::DVector;
+use crate::field::Ring;
+use crate::matrix::Matrix;
+
+/// Compressed storage shared by CSR (major axis = rows) and CSC (major axis =
+/// columns). Minor indices are sorted and unique within each major slice.
+#[derive(Debug, Clone, PartialEq)]
+struct Compressed<K> {
+    major: usize,
+    minor: usize,
+    offsets: Vec<usize>,
+    indices: Vec<usize>,
+    values: Vec<K>,
+}
+
+impl<K: Ring> Compressed<K> {
+    /// Builds from `(major, minor, value)` triplets, summing duplicates and
+    /// dropping exact zeros.
+    fn from_triplets(major: usize, minor: usize, mut triplets: Vec<(usize, usize, K)>) -> Self {
+        triplets.sort_by_key(|&(i, j, _)| (i, j));
+
+        let mut offsets = vec![0; major + 1];
+        let mut indices: Vec<usize> = Vec::with_capacity(triplets.len());
+        let mut values: Vec<K> = Vec::with_capacity(triplets.len());
+        let mut last = None;
+        for (i, j, value) in triplets {
+            if last == Some((i, j)) {
+                *values.last_mut().unwrap() += value;
+            } else {
+                offsets[i + 1] += 1;
+                indices.push(j);
+                values.push(value);
+                last = Some((i, j));
+            }
+        }
+        for i in 0..major {
+            offsets[i + 1] += offsets[i];
+        }
+
+        let mut result = Compressed {
+            major,
+            minor,
+            offsets,
+            indices,
+            values,
+        };
+        result.prune();
+        result
+    }
+
+    fn prune(&mut self) {
+        let mut kept = 0;
+        let mut start = 0;
+        for i in 0..self.major {
+            let end = self.offsets[i + 1];
+            for k in start..end {
+                if self.values[k] != K::zero() {
+                    self.indices[kept] = self.indices[k];
+                    self.values[kept] = self.values[k];
+                    kept += 1;
+                }
+            }
+            start = end;
+            self.offsets[i + 1] = kept;
+        }
+        self.indices.truncate(kept);
+        self.values.truncate(kept);
+    }
+
+    fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    fn slice(&self, i: usize) -> impl Iterator<Item = (usize, K)> + '_ {
+        let range = self.offsets[i]..self.offsets[i + 1];
+        self.indices[range.clone()]
+            .iter()
+            .copied()
+            .zip(self.values[range].iter().copied())
+    }
+
+    fn get(&self, i: usize, j: usize) -> K {
+        assert!(i < self.major && j < self.minor, "Index out of bounds");
+        let range = self.offsets[i]..self.offsets[i + 1];
+        match self.indices[range.clone()].binary_search(&j) {
+            Ok(k) => self.values[range.start + k],
+            Err(_) => K::zero(),
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (usize, usize, K)> + '_ {
+        (0..self.major).flat_map(move |i| self.slice(i).map(move |(j, value)| (i, j, value)))
+    }
+
+    /// The same entries stored along the other axis.
+    fn transpose(&self) -> Self {
+        let mut offsets = vec![0; self.minor + 1];
+        for &j in &self.indices {
+            offsets[j + 1] += 1;
+        }
+        for j in 0..self.minor {
+            offsets[j + 1] += offsets[j];
+        }
+
+        let mut next = offsets.clone();
+        let mut indices = vec![0; self.nnz()];
+        let mut values = vec![K::zero(); self.nnz()];
+        for (i, j, value) in self.iter() {
+            indices[next[j]] = i;
+            values[next[j]] = value;
+            next[j] += 1;
+        }
+
+        Compressed {
+            major: self.minor,
+            minor: self.major,
+            offsets,
+            indices,
+            values,
+        }
+    }
+
+    /// Gustavson's algorithm: slice `i` of the result accumulates
+    /// `lhs[i][k] * rhs[k]` over the non-zeros `k` of `lhs[i]`.
+    fn product(lhs: &Self, rhs: &Self) -> Self {
+        assert_eq!(lhs.minor, rhs.major, "Matrix dimensions are incompatible");
+
+        let mut offsets = vec![0; lhs.major + 1];
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+        let mut accumulator = vec![K::zero(); rhs.minor];
+        let mut occupied = vec![false; rhs.minor];
+        let mut pattern = Vec::new();
+        for i in 0..lhs.major {
+            for (k, a) in lhs.slice(i) {
+                for (j, b) in rhs.slice(k) {
+                    if !occupied[j] {
+                        occupied[j] = true;
+                        pattern.push(j);
+                    }
+                    accumulator[j] += a * b;
+                }
+            }
+
+            pattern.sort_unstable();
+            for j in pattern.drain(..) {
+                if accumulator[j] != K::zero() {
+                    indices.push(j);
+                    values.push(accumulator[j]);
+                }
+                accumulator[j] = K::zero();
+                occupied[j] = false;
+            }
+            offsets[i + 1] = indices.len();
+        }
+
+        Compressed {
+            major: lhs.major,
+            minor: rhs.minor,
+            offsets,
+            indices,
+            values,
+        }
+    }
+
+    fn trace(&self) -> K {
+        assert_eq!(self.major, self.minor, "Matrix must be square");
+        (0..self.major).fold(K::zero(), |acc, i| acc + self.get(i, i))
+    }
+}
+
+/// Coordinate-format builder: entries may be pushed in any order and
+/// duplicates are summed on compression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooMatrix<K> {
+    rows: usize,
+    cols: usize,
+    entries: Vec<(usize, usize, K)>,
+}
+
+impl<K: Ring> CooMatrix<K> {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        CooMatrix {
+            rows,
+            cols,
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: usize, col: usize, value: K) {
+        assert!(row < self.rows && col < self.cols, "Index out of bounds");
+        self.entries.push((row, col, value));
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, K)> + '_ {
+        self.entries.iter().copied()
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<K> {
+        CsrMatrix {
+            storage: Compressed::from_triplets(self.rows, self.cols, self.entries.clone()),
+        }
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<K> {
+        let entries = self.iter().map(|(i, j, value)| (j, i, value)).collect();
+        CscMatrix {
+            storage: Compressed::from_triplets(self.cols, self.rows, entries),
+        }
+    }
+
+    pub fn to_dense(&self) -> DMatrix<K> {
+        let mut result = DMatrix::zeros(self.rows, self.cols);
+        for (i, j, value) in self.iter() {
+            result[i][j] += value;
+        }
+        result
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix<K> {
+    storage: Compressed<K>,
+}
+
+impl<K: Ring> CsrMatrix<K> {
+    pub fn rows(&self) -> usize {
+        self.storage.major
+    }
+
+    pub fn cols(&self) -> usize {
+        self.storage.minor
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.storage.nnz()
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> K {
+        self.storage.get(row, col)
+    }
+
+    /// Stored entries as `(row, col, value)`, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, K)> + '_ {
+        self.storage.iter()
+    }
+
+    pub fn row(&self, row: usize) -> impl Iterator<Item = (usize, K)> + '_ {
+        self.storage.slice(row)
+    }
+
+    pub fn mul_vec(&self, rhs: &[K]) -> DVector<K> {
+        assert_eq!(
+            self.cols(),
+            rhs.len(),
+            "Vector dimension must match columns"
+        );
+        DVector::new(
+            (0..self.rows())
+                .map(|i| {
+                    self.row(i)
+                        .fold(K::zero(), |acc, (j, value)| acc + value * rhs[j])
+                })
+                .collect(),
+        )
+    }
+
+    pub fn mul_dense(&self, rhs: &DMatrix<K>) -> DMatrix<K> {
+        assert_eq!(
+            self.cols(),
+            rhs.rows(),
+            "Matrix dimensions are incompatible"
+        );
+        let mut result = DMatrix::zeros(self.rows(), rhs.cols());
+        for (i, k, a) in self.iter() {
+            for (elt, &b) in result[i].iter_mut().zip(&rhs[k]) {
+                *elt += a * b;
+            }
+        }
+        result
+    }
+
+    pub fn mul_mat(&self, rhs: &CsrMatrix<K>) -> CsrMatrix<K> {
+        CsrMatrix {
+            storage: Compressed::product(&self.storage, &rhs.storage),
+        }
+    }
+
+    pub fn transpose(&self) -> CsrMatrix<K> {
+        CsrMatrix {
+            storage: self.storage.transpose(),
+        }
+    }
+
+    pub fn trace(&self) -> K {
+        self.storage.trace()
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<K> {
+        CscMatrix {
+            storage: self.storage.transpose(),
+        }
+    }
+
+    pub fn to_dense(&self) -> DMatrix<K> {
+        let mut result = DMatrix::zeros(self.rows(), self.cols());
+        for (i, j, value) in self.iter() {
+            result[i][j] = value;
+        }
+        result
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CscMatrix<K> {
+    storage: Compressed<K>,
+}
+
+impl<K: Ring> CscMatrix<K> {
+    pub fn rows(&self) -> usize {
+        self.storage.minor
+    }
+
+    pub fn cols(&self) -> usize {
+        self.storage.major
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.storage.nnz()
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> K {
+        self.storage.get(col, row)
+    }
+
+    /// Stored entries as `(row, col, value)`, in column-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, K)> + '_ {
+        self.storage.iter().map(|(j, i, value)| (i, j, value))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = (usize, K)> + '_ {
+        self.storage.slice(col)
+    }
+
+    pub fn mul_vec(&self, rhs: &[K]) -> DVector<K> {
+        assert_eq!(
+            self.cols(),
+            rhs.len(),
+            "Vector dimension must match columns"
+        );
+        let mut result = DVector::zeros(self.rows());
+        for (i, j, value) in self.iter() {
+            result[i] += value * rhs[j];
+        }
+        result
+    }
+
+    pub fn mul_dense(&self, rhs: &DMatrix<K>) -> DMatrix<K> {
+        assert_eq!(
+            self.cols(),
+            rhs.rows(),
+            "Matrix dimensions are incompatible"
+        );
+        let mut result = DMatrix::zeros(self.rows(), rhs.cols());
+        for (i, k, a) in self.iter() {
+            for (elt, &b) in result[i].iter_mut().zip(&rhs[k]) {
+                *elt += a * b;
+            }
+        }
+        result
+    }
+
+    /// Column `j` of `A·B` combines the columns of `A` selected by column `j`
+    /// of `B`, which is the CSR product with the operands swapped.
+    pub fn mul_mat(&self, rhs: &CscMatrix<K>) -> CscMatrix<K> {
+        CscMatrix {
+            storage: Compressed::product(&rhs.storage, &self.storage),
+        }
+    }
+
+    pub fn transpose(&self) -> CscMatrix<K> {
+        CscMatrix {
+            storage: self.storage.transpose(),
+        }
+    }
+
+    pub fn trace(&self) -> K {
+        self.storage.trace()
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<K> {
+        CsrMatrix {
+            storage: self.storage.transpose(),
+        }
+    }
+
+    pub fn to_dense(&self) -> DMatrix<K> {
+        let mut result = DMatrix::zeros(self.rows(), self.cols());
+        for (i, j, value) in self.iter() {
+            result[i][j] = value;
+        }
+        result
+    }
+}
+
+impl<K: Ring> From<&DMatrix<K>> for CooMatrix<K> {
+    fn from(matrix: &DMatrix<K>) -> Self {
+        let mut result = CooMatrix::new(matrix.rows(), matrix.cols());
+        for i in 0..matrix.rows() {
+            for (j, &value) in matrix[i].iter().enumerate() {
+                if value != K::zero() {
+                    result.push(i, j, value);
+                }
+            }
+        }
+        result
+    }
+}
+
+impl<K: Ring> From<&DMatrix<K>> for CsrMatrix<K> {
+    fn from(matrix: &DMatrix<K>) -> Self {
+        CooMatrix::from(matrix).to_csr()
+    }
+}
+
+impl<K: Ring> From<&DMatrix<K>> for CscMatrix<K> {
+    fn from(matrix: &DMatrix<K>) -> Self {
+        CooMatrix::from(matrix).to_csc()
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> From<Matrix<K, M, N>> for CsrMatrix<K> {
+    fn from(matrix: Matrix<K, M, N>) -> Self {
+        CsrMatrix::from(&DMatrix::from(matrix))
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> From<Matrix<K, M, N>> for CscMatrix<K> {
+    fn from(matrix: Matrix<K, M, N>) -> Self {
+        CscMatrix::from(&DMatrix::from(matrix))
+    }
+}
+
+impl<K: Ring> From<CooMatrix<K>> for CsrMatrix<K> {
+    fn from(matrix: CooMatrix<K>) -> Self {
+        matrix.to_csr()
+    }
+}
+
+impl<K: Ring> From<CooMatrix<K>> for CscMatrix<K> {
+    fn from(matrix: CooMatrix<K>) -> Self {
+        matrix.to_csc()
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> TryFrom<&CsrMatrix<K>> for Matrix<K, M, N> {
+    type Error = &'static str;
+
+    fn try_from(matrix: &CsrMatrix<K>) -> Result<Self, Self::Error> {
+        Matrix::try_from(matrix.to_dense())
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> TryFrom<&CscMatrix<K>> for Matrix<K, M, N> {
+    type Error = &'static str;
+
+    fn try_from(matrix: &CscMatrix<K>) -> Result<Self, Self::Error> {
+        Matrix::try_from(matrix.to_dense())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector::Vector;
+    use approx::assert_abs_diff_eq;
+    use rand::prelude::*;
+
+    const NB_TESTCASE_MATRICES: usize = 100;
+    const THRESHOLD: f64 = 1e-8;
+
+    /// Roughly 80% of the entries are zero.
+    fn generate_random_sparse(rows: usize, cols: usize) -> DMatrix<f64> {
+        let mut rng = rand::thread_rng();
+        DMatrix::new(
+            rows,
+            cols,
+            (0..rows * cols)
+                .map(|_| {
+                    if rng.gen_bool(0.2) {
+                        rng.gen_range(-1.0..1.0)
+                    } else {
+                        0.0
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    fn assert_dense_eq(matrix: &DMatrix<f64>, expected: &DMatrix<f64>) {
+        assert_eq!(
+            (matrix.rows(), matrix.cols()),
+            (expected.rows(), expected.cols())
+        );
+        for (&a, &b) in matrix.as_slice().iter().zip(expected.as_slice()) {
+            assert_abs_diff_eq!(a, b, epsilon = THRESHOLD);
+        }
+    }
+
+    #[test]
+    fn sparse_coo_builder() {
+        let mut coo = CooMatrix::new(3, 3);
+        coo.push(2, 0, 4);
+        coo.push(0, 1, 1);
+        coo.push(2, 0, -1);
+        coo.push(1, 1, 5);
+        coo.push(0, 2, 2);
+        coo.push(0, 2, -2);
+
+        let csr = coo.to_csr();
+        assert_eq!(csr.nnz(), 3);
+        assert_eq!(
+            csr.iter().collect::<Vec<_>>(),
+            vec![(0, 1, 1), (1, 1, 5), (2, 0, 3)]
+        );
+        assert_eq!(
+            coo.to_csc().iter().collect::<Vec<_>>(),
+            vec![(2, 0, 3), (0, 1, 1), (1, 1, 5)]
+        );
+        assert_eq!(csr.get(2, 0), 3);
+        assert_eq!(csr.get(0, 2), 0);
+        assert_eq!(csr.trace(), 5);
+        assert_eq!(csr.to_dense(), coo.to_dense());
+    }
+
+    #[test]
+    fn sparse_dense_products() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let (m, n, p) = (
+                rng.gen_range(1..12),
+                rng.gen_range(1..12),
+                rng.gen_range(1..12),
+            );
+            let a = generate_random_sparse(m, n);
+            let b = generate_random_sparse(n, p);
+            let v = DVector::new((0..n).map(|_| rng.gen_range(-1.0..1.0)).collect());
+
+            let (csr, csc) = (CsrMatrix::from(&a), CscMatrix::from(&a));
+            let expected = a.mul_vec(&v);
+            for result in [csr.mul_vec(&v), csc.mul_vec(&v)] {
+                for (&x, &y) in result.iter().zip(expected.iter()) {
+                    assert_abs_diff_eq!(x, y, epsilon = THRESHOLD);
+                }
+            }
+
+            let expected = a.mul_mat(&b);
+            assert_dense_eq(&csr.mul_dense(&b), &expected);
+            assert_dense_eq(&csc.mul_dense(&b), &expected);
+            assert_dense_eq(&csr.mul_mat(&CsrMatrix::from(&b)).to_dense(), &expected);
+            assert_dense_eq(&csc.mul_mat(&CscMatrix::from(&b)).to_dense(), &expected);
+        }
+    }
+
+    #[test]
+    fn sparse_transpose_trace() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let (m, n) = (rng.gen_range(1..12), rng.gen_range(1..12));
+            let a = generate_random_sparse(m, n);
+            let csr = CsrMatrix::from(&a);
+
+            assert_eq!(csr.transpose().to_dense(), a.transpose());
+            assert_eq!(csr.to_csc().transpose().to_dense(), a.transpose());
+            assert_eq!(csr.to_csc().to_csr(), csr);
+
+            let square = generate_random_sparse(n, n);
+            assert_abs_diff_eq!(
+                CscMatrix::from(&square).trace(),
+                square.trace(),
+                epsilon = THRESHOLD
+            );
+        }
+    }
+
+    #[test]
+    fn sparse_static_interop() {
+        let u = Matrix::new([[0., 2., 0.], [1., 0., 0.], [0., 0., 3.]]);
+        let csr = CsrMatrix::from(u);
+
+        assert_eq!(
+            csr.mul_vec(&Vector::new([1., 2., 3.])),
+            DVector::new(vec![4., 1., 9.])
+        );
+        assert_eq!(Matrix::<f64, 3, 3>::try_from(&csr.to_csc()), Ok(u));
+        assert!(Matrix::<f64, 2, 3>::try_from(&csr).is_err());
+    }
+}
@@ -0,0 +1,681 @@
+use crate::cholesky::LDL;
+use crate::field::{Field, Real, Ring};
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+
+const fn packed_len(n: usize) -> usize {
+    n * (n + 1) / 2
+}
+
+/// Upper triangular matrix packed row by row: row `i` stores columns `i..N`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpperTriangular<K, const N: usize> {
+    data: Vec<K>,
+}
+
+/// Lower triangular matrix packed row by row: row `i` stores columns `0..=i`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LowerTriangular<K, const N: usize> {
+    data: Vec<K>,
+}
+
+/// Symmetric matrix storing only its lower triangle, packed like
+/// `LowerTriangular`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Symmetric<K, const N: usize> {
+    data: Vec<K>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Diagonal<K, const N: usize> {
+    data: [K; N],
+}
+
+fn lower_index(row: usize, col: usize) -> usize {
+    packed_len(row) + col
+}
+
+/// Rejects a pivot about to be divided by, as `lu::check_pivots` does.
+fn check_pivot<K: Ring>(pivot: K) -> Result<(), &'static str> {
+    if pivot == K::zero() {
+        return Err("Matrix is singular");
+    }
+    if pivot.may_be_zero() {
+        return Err("Matrix is possibly singular");
+    }
+    Ok(())
+}
+
+impl<K: Ring, const N: usize> UpperTriangular<K, N> {
+    pub fn new(data: Vec<K>) -> Self {
+        assert_eq!(
+            data.len(),
+            packed_len(N),
+            "Number of elements must match the packed size"
+        );
+        UpperTriangular { data }
+    }
+
+    pub fn zeros() -> Self {
+        UpperTriangular::new(vec![K::zero(); packed_len(N)])
+    }
+
+    fn index(row: usize, col: usize) -> usize {
+        row * N - packed_len(row) + col
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> K {
+        assert!(row < N && col < N, "Index out of bounds");
+        if col >= row {
+            self.data[Self::index(row, col)]
+        } else {
+            K::zero()
+        }
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: K) {
+        assert!(row <= col && col < N, "Index outside the upper triangle");
+        self.data[Self::index(row, col)] = value;
+    }
+
+    fn row(&self, row: usize) -> impl Iterator<Item = (usize, K)> + '_ {
+        (row..N).map(move |col| (col, self.get(row, col)))
+    }
+
+    pub fn transpose(&self) -> LowerTriangular<K, N> {
+        let mut result = LowerTriangular::zeros();
+        for i in 0..N {
+            for j in i..N {
+                result.set(j, i, self.get(i, j));
+            }
+        }
+        result
+    }
+
+    pub fn determinant(&self) -> K {
+        (0..N).fold(K::one(), |acc, i| acc * self.get(i, i))
+    }
+}
+
+impl<K: Field, const N: usize> UpperTriangular<K, N> {
+    /// Back substitution.
+    pub fn solve(&self, rhs: &Vector<K, N>) -> Result<Vector<K, N>, &'static str> {
+        let mut result = *rhs;
+        for i in (0..N).rev() {
+            let pivot = self.get(i, i);
+            check_pivot(pivot)?;
+            let value = self
+                .row(i)
+                .skip(1)
+                .fold(result[i], |acc, (j, a)| acc - a * result[j]);
+            result[i] = value / pivot;
+        }
+        Ok(result)
+    }
+
+    /// The inverse stays upper triangular; column `j` only needs rows `0..=j`.
+    pub fn inverse(&self) -> Result<UpperTriangular<K, N>, &'static str> {
+        let mut result = UpperTriangular::zeros();
+        for j in 0..N {
+            for i in (0..=j).rev() {
+                let pivot = self.get(i, i);
+                check_pivot(pivot)?;
+                let start = if i == j { K::one() } else { K::zero() };
+                let value =
+                    ((i + 1)..=j).fold(start, |acc, k| acc - self.get(i, k) * result.get(k, j));
+                result.set(i, j, value / pivot);
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<K: Ring, const N: usize> LowerTriangular<K, N> {
+    pub fn new(data: Vec<K>) -> Self {
+        assert_eq!(
+            data.len(),
+            packed_len(N),
+            "Number of elements must match the packed size"
+        );
+        LowerTriangular { data }
+    }
+
+    pub fn zeros() -> Self {
+        LowerTriangular::new(vec![K::zero(); packed_len(N)])
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> K {
+        assert!(row < N && col < N, "Index out of bounds");
+        if col <= row {
+            self.data[lower_index(row, col)]
+        } else {
+            K::zero()
+        }
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: K) {
+        assert!(col <= row && row < N, "Index outside the lower triangle");
+        self.data[lower_index(row, col)] = value;
+    }
+
+    fn row(&self, row: usize) -> impl Iterator<Item = (usize, K)> + '_ {
+        (0..=row).map(move |col| (col, self.get(row, col)))
+    }
+
+    pub fn transpose(&self) -> UpperTriangular<K, N> {
+        let mut result = UpperTriangular::zeros();
+        for i in 0..N {
+            for j in 0..=i {
+                result.set(j, i, self.get(i, j));
+            }
+        }
+        result
+    }
+
+    pub fn determinant(&self) -> K {
+        (0..N).fold(K::one(), |acc, i| acc * self.get(i, i))
+    }
+}
+
+impl<K: Field, const N: usize> LowerTriangular<K, N> {
+    /// Forward substitution.
+    pub fn solve(&self, rhs: &Vector<K, N>) -> Result<Vector<K, N>, &'static str> {
+        let mut result = *rhs;
+        for i in 0..N {
+            let pivot = self.get(i, i);
+            check_pivot(pivot)?;
+            let value = self
+                .row(i)
+                .take(i)
+                .fold(result[i], |acc, (j, a)| acc - a * result[j]);
+            result[i] = value / pivot;
+        }
+        Ok(result)
+    }
+
+    pub fn inverse(&self) -> Result<LowerTriangular<K, N>, &'static str> {
+        Ok(self.transpose().inverse()?.transpose())
+    }
+}
+
+impl<K: Ring, const N: usize> Symmetric<K, N> {
+    pub fn new(data: Vec<K>) -> Self {
+        assert_eq!(
+            data.len(),
+            packed_len(N),
+            "Number of elements must match the packed size"
+        );
+        Symmetric { data }
+    }
+
+    pub fn zeros() -> Self {
+        Symmetric::new(vec![K::zero(); packed_len(N)])
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> K {
+        assert!(row < N && col < N, "Index out of bounds");
+        self.data[lower_index(row.max(col), row.min(col))]
+    }
+
+    /// Sets both `(row, col)` and `(col, row)`.
+    pub fn set(&mut self, row: usize, col: usize, value: K) {
+        assert!(row < N && col < N, "Index out of bounds");
+        self.data[lower_index(row.max(col), row.min(col))] = value;
+    }
+
+    fn row(&self, row: usize) -> impl Iterator<Item = (usize, K)> + '_ {
+        (0..N).map(move |col| (col, self.get(row, col)))
+    }
+
+    pub fn transpose(&self) -> Symmetric<K, N> {
+        self.clone()
+    }
+}
+
+impl<K: Ring, const N: usize> Symmetric<K, N> {
+    /// `P·A·Pᵀ` for the transposition `P` of `a` and `b`, which keeps the
+    /// matrix symmetric.
+    pub(crate) fn swap_symmetric(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        for m in (0..N).filter(|&m| m != a && m != b) {
+            let (x, y) = (self.get(a, m), self.get(b, m));
+            self.set(a, m, y);
+            self.set(b, m, x);
+        }
+        let (x, y) = (self.get(a, a), self.get(b, b));
+        self.set(a, a, y);
+        self.set(b, b, x);
+    }
+}
+
+impl<K: Real, const N: usize> Symmetric<K, N> {
+    /// Through the Bunch-Kaufman `LDL` factorization, run on the packed
+    /// lower triangle.
+    pub fn determinant(&self) -> K {
+        LDL::from_packed(self.clone()).determinant()
+    }
+
+    /// The inverse of a symmetric matrix is symmetric, so only its lower
+    /// triangle is kept.
+    pub fn inverse(&self) -> Result<Symmetric<K, N>, &'static str> {
+        let ldl = LDL::from_packed(self.clone());
+        let mut result = Symmetric::zeros();
+        for col in 0..N {
+            let mut unit = Vector::new([K::zero(); N]);
+            unit[col] = K::one();
+            let column = ldl.solve(&unit)?;
+            for row in col..N {
+                result.set(row, col, column[row]);
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<K: Ring, const N: usize> Diagonal<K, N> {
+    pub fn new(data: [K; N]) -> Self {
+        Diagonal { data }
+    }
+
+    pub fn diag(&self) -> &[K; N] {
+        &self.data
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> K {
+        assert!(row < N && col < N, "Index out of bounds");
+        if row == col {
+            self.data[row]
+        } else {
+            K::zero()
+        }
+    }
+
+    fn row(&self, row: usize) -> impl Iterator<Item = (usize, K)> + '_ {
+        std::iter::once((row, self.data[row]))
+    }
+
+    pub fn transpose(&self) -> Diagonal<K, N> {
+        *self
+    }
+
+    pub fn determinant(&self) -> K {
+        self.data.iter().fold(K::one(), |acc, &x| acc * x)
+    }
+}
+
+impl<K: Field, const N: usize> Diagonal<K, N> {
+    pub fn solve(&self, rhs: &Vector<K, N>) -> Result<Vector<K, N>, &'static str> {
+        Ok(self.inverse()?.mul_vec(rhs))
+    }
+
+    pub fn inverse(&self) -> Result<Diagonal<K, N>, &'static str> {
+        for &x in &self.data {
+            check_pivot(x)?;
+        }
+        Ok(Diagonal::new(self.data.map(|x| K::one() / x)))
+    }
+}
+
+macro_rules! impl_structured {
+    ($($t:ident),*) => {
+        $(
+            impl<K: Ring, const N: usize> $t<K, N> {
+                pub fn mul_vec(&self, rhs: &Vector<K, N>) -> Vector<K, N> {
+                    let mut result = [K::zero(); N];
+                    for (i, elt) in result.iter_mut().enumerate() {
+                        *elt = self.row(i).fold(K::zero(), |acc, (j, a)| acc + a * rhs[j]);
+                    }
+                    Vector::new(result)
+                }
+
+                pub fn mul_mat<const P: usize>(&self, rhs: &Matrix<K, N, P>) -> Matrix<K, N, P> {
+                    let mut result = [[K::zero(); P]; N];
+                    for (i, row) in result.iter_mut().enumerate() {
+                        for (k, a) in self.row(i) {
+                            for (elt, &b) in row.iter_mut().zip(&rhs[k]) {
+                                *elt += a * b;
+                            }
+                        }
+                    }
+                    Matrix::new(result)
+                }
+            }
+
+            impl<K: Ring, const N: usize> From<&$t<K, N>> for Matrix<K, N, N> {
+                fn from(matrix: &$t<K, N>) -> Self {
+                    let mut result = [[K::zero(); N]; N];
+                    for (i, row) in result.iter_mut().enumerate() {
+                        for (j, value) in matrix.row(i) {
+                            row[j] = value;
+                        }
+                    }
+                    Matrix::new(result)
+                }
+            }
+
+            impl<K: Ring, const N: usize> From<$t<K, N>> for Matrix<K, N, N> {
+                fn from(matrix: $t<K, N>) -> Self {
+                    Matrix::from(&matrix)
+                }
+            }
+        )*
+    };
+}
+
+impl_structured!(UpperTriangular, LowerTriangular, Symmetric, Diagonal);
+
+impl<K: Ring, const N: usize> TryFrom<&Matrix<K, N, N>> for UpperTriangular<K, N> {
+    type Error = &'static str;
+
+    fn try_from(matrix: &Matrix<K, N, N>) -> Result<Self, Self::Error> {
+        let mut result = UpperTriangular::zeros();
+        for i in 0..N {
+            for j in 0..N {
+                if j >= i {
+                    result.set(i, j, matrix[i][j]);
+                } else if matrix[i][j] != K::zero() {
+                    return Err("Matrix is not upper triangular");
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<K: Ring, const N: usize> TryFrom<&Matrix<K, N, N>> for LowerTriangular<K, N> {
+    type Error = &'static str;
+
+    fn try_from(matrix: &Matrix<K, N, N>) -> Result<Self, Self::Error> {
+        let mut result = LowerTriangular::zeros();
+        for i in 0..N {
+            for j in 0..N {
+                if j <= i {
+                    result.set(i, j, matrix[i][j]);
+                } else if matrix[i][j] != K::zero() {
+                    return Err("Matrix is not lower triangular");
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<K: Ring, const N: usize> TryFrom<&Matrix<K, N, N>> for Symmetric<K, N> {
+    type Error = &'static str;
+
+    fn try_from(matrix: &Matrix<K, N, N>) -> Result<Self, Self::Error> {
+        let mut result = Symmetric::zeros();
+        for i in 0..N {
+            for j in 0..=i {
+                if matrix[i][j] != matrix[j][i] {
+                    return Err("Matrix is not symmetric");
+                }
+                result.set(i, j, matrix[i][j]);
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<K: Ring, const N: usize> TryFrom<&Matrix<K, N, N>> for Diagonal<K, N> {
+    type Error = &'static str;
+
+    fn try_from(matrix: &Matrix<K, N, N>) -> Result<Self, Self::Error> {
+        let mut result = [K::zero(); N];
+        for i in 0..N {
+            for j in 0..N {
+                if i == j {
+                    result[i] = matrix[i][i];
+                } else if matrix[i][j] != K::zero() {
+                    return Err("Matrix is not diagonal");
+                }
+            }
+        }
+        Ok(Diagonal::new(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::Interval;
+    use approx::assert_abs_diff_eq;
+    use rand::prelude::*;
+
+    const NB_TESTCASE_MATRICES: usize = 100;
+    const THRESHOLD: f64 = 1e-8;
+
+    fn generate_random_matrix<const M: usize, const N: usize>() -> Matrix<f64, M, N> {
+        let mut rng = rand::thread_rng();
+        let mut data = [[0.0; N]; M];
+        for row in &mut data {
+            for elem in row {
+                *elem = rng.gen_range(-1.0..1.0);
+            }
+        }
+        Matrix::new(data)
+    }
+
+    /// Keeps the entries `(i, j)` selected by `keep` and shifts the diagonal
+    /// away from zero so inverses stay well conditioned.
+    fn masked<const N: usize>(keep: impl Fn(usize, usize) -> bool) -> Matrix<f64, N, N> {
+        let mut result = generate_random_matrix::<N, N>();
+        for i in 0..N {
+            for j in 0..N {
+                if !keep(i, j) {
+                    result[i][j] = 0.0;
+                }
+            }
+            result[i][i] += 4.0f64.copysign(result[i][i]);
+        }
+        result
+    }
+
+    fn assert_matrix_eq<const M: usize, const N: usize>(
+        matrix: &Matrix<f64, M, N>,
+        expected: &Matrix<f64, M, N>,
+    ) {
+        for i in 0..M {
+            for j in 0..N {
+                assert_abs_diff_eq!(matrix[i][j], expected[i][j], epsilon = THRESHOLD);
+            }
+        }
+    }
+
+    fn check_against_dense<const N: usize>(
+        dense: &Matrix<f64, N, N>,
+        structured: Matrix<f64, N, N>,
+        transpose: Matrix<f64, N, N>,
+        determinant: f64,
+        inverse: Matrix<f64, N, N>,
+        products: (Vector<f64, N>, Matrix<f64, N, 3>),
+        operands: (&Vector<f64, N>, &Matrix<f64, N, 3>),
+    ) {
+        assert_eq!(&structured, dense);
+        assert_eq!(transpose, dense.transpose());
+        assert_abs_diff_eq!(determinant, dense.determinant(), epsilon = THRESHOLD);
+        assert_matrix_eq(&inverse, &dense.inverse().unwrap());
+        assert_eq!(products.0, dense.mul_vec(operands.0));
+        assert_matrix_eq(&products.1, &dense.mul_mat(operands.1));
+    }
+
+    fn test_structured<const N: usize>() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let v = Vector::new(generate_random_matrix::<1, N>()[0]);
+            let b = generate_random_matrix::<N, 3>();
+
+            let dense = masked::<N>(|i, j| j >= i);
+            let upper = UpperTriangular::try_from(&dense).unwrap();
+            check_against_dense(
+                &dense,
+                Matrix::from(&upper),
+                Matrix::from(upper.transpose()),
+                upper.determinant(),
+                Matrix::from(upper.inverse().unwrap()),
+                (upper.mul_vec(&v), upper.mul_mat(&b)),
+                (&v, &b),
+            );
+            let x = upper.solve(&v).unwrap();
+            for (&a, &b) in upper.mul_vec(&x).iter().zip(v.iter()) {
+                assert_abs_diff_eq!(a, b, epsilon = THRESHOLD);
+            }
+
+            let dense = masked::<N>(|i, j| j <= i);
+            let lower = LowerTriangular::try_from(&dense).unwrap();
+            check_against_dense(
+                &dense,
+                Matrix::from(&lower),
+                Matrix::from(lower.transpose()),
+                lower.determinant(),
+                Matrix::from(lower.inverse().unwrap()),
+                (lower.mul_vec(&v), lower.mul_mat(&b)),
+                (&v, &b),
+            );
+
+            let mut dense = masked::<N>(|_, _| true);
+            for i in 0..N {
+                for j in 0..i {
+                    dense[j][i] = dense[i][j];
+                }
+            }
+            let symmetric = Symmetric::try_from(&dense).unwrap();
+            check_against_dense(
+                &dense,
+                Matrix::from(&symmetric),
+                Matrix::from(symmetric.transpose()),
+                symmetric.determinant(),
+                Matrix::from(symmetric.inverse().unwrap()),
+                (symmetric.mul_vec(&v), symmetric.mul_mat(&b)),
+                (&v, &b),
+            );
+
+            let dense = masked::<N>(|i, j| i == j);
+            let diagonal = Diagonal::try_from(&dense).unwrap();
+            check_against_dense(
+                &dense,
+                Matrix::from(&diagonal),
+                Matrix::from(diagonal.transpose()),
+                diagonal.determinant(),
+                Matrix::from(diagonal.inverse().unwrap()),
+                (diagonal.mul_vec(&v), diagonal.mul_mat(&b)),
+                (&v, &b),
+            );
+        }
+    }
+
+    #[test]
+    fn structured_1() {
+        test_structured::<1>();
+    }
+
+    #[test]
+    fn structured_4() {
+        test_structured::<4>();
+    }
+
+    #[test]
+    fn structured_7() {
+        test_structured::<7>();
+    }
+
+    #[test]
+    fn structured_conversions() {
+        let u = Matrix::new([[1, 2, 3], [0, 4, 5], [0, 0, 6]]);
+        let upper = UpperTriangular::<i64, 3>::try_from(&u).unwrap();
+        assert_eq!(upper, UpperTriangular::new(vec![1, 2, 3, 4, 5, 6]));
+        assert_eq!(upper.determinant(), 24);
+        assert_eq!(Matrix::from(upper), u);
+
+        assert_eq!(
+            LowerTriangular::<i64, 3>::try_from(&u),
+            Err("Matrix is not lower triangular")
+        );
+        assert_eq!(
+            Symmetric::<i64, 3>::try_from(&u),
+            Err("Matrix is not symmetric")
+        );
+        assert_eq!(
+            Diagonal::<i64, 3>::try_from(&u),
+            Err("Matrix is not diagonal")
+        );
+
+        let s = Matrix::new([[2, 1], [1, 3]]);
+        assert_eq!(
+            Symmetric::<i64, 2>::try_from(&s),
+            Ok(Symmetric::new(vec![2, 1, 3]))
+        );
+        assert_eq!(
+            UpperTriangular::<f64, 2>::new(vec![1., 2., 0.]).inverse(),
+            Err("Matrix is singular")
+        );
+
+        let (one, x) = (Interval::point(1.), Interval::new(-1., 1.));
+        let b = Vector::new([one; 2]);
+        let upper = UpperTriangular::<Interval, 2>::new(vec![one, one, x]);
+        assert_eq!(upper.solve(&b), Err("Matrix is possibly singular"));
+        assert_eq!(upper.inverse(), Err("Matrix is possibly singular"));
+        let lower = upper.transpose();
+        assert_eq!(lower.solve(&b), Err("Matrix is possibly singular"));
+        assert_eq!(lower.inverse(), Err("Matrix is possibly singular"));
+        let diagonal = Diagonal::new([one, x]);
+        assert_eq!(diagonal.solve(&b), Err("Matrix is possibly singular"));
+        assert_eq!(
+            Diagonal::new([Interval::point(0.), x]).inverse(),
+            Err("Matrix is singular")
+        );
+    }
+
+    #[test]
+    fn symmetric_indefinite() {
+        // A zero diagonal needs a 2×2 pivot.
+        let swap = Symmetric::<f64, 2>::new(vec![0., 1., 0.]);
+        assert_eq!(swap.determinant(), -1.);
+        assert_eq!(swap.inverse(), Ok(swap.clone()));
+        let rank_one = Symmetric::<f64, 3>::new(vec![1., 1., 1., 1., 1., 1.]);
+        assert_eq!(rank_one.determinant(), 0.);
+        assert_eq!(rank_one.inverse(), Err("Matrix is singular"));
+
+        // Pivoting on the tiny diagonal would lose det = e² - 1 entirely.
+        for e in [1e-17, 1e-13, 1e-8] {
+            let symmetric = Symmetric::<f64, 2>::new(vec![e, 1., e]);
+            let determinant = e * e - 1.;
+            assert_abs_diff_eq!(symmetric.determinant(), determinant, epsilon = 1e-15);
+            let inverse = Matrix::from(symmetric.inverse().unwrap());
+            let expected = Matrix::new([[e, -1.], [-1., e]]);
+            for i in 0..2 {
+                for j in 0..2 {
+                    assert_abs_diff_eq!(
+                        inverse[i][j],
+                        expected[i][j] / determinant,
+                        epsilon = 1e-15
+                    );
+                }
+            }
+        }
+
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let mut dense = generate_random_matrix::<5, 5>();
+            for i in 0..5 {
+                dense[i][i] *= 1e-12;
+                for j in 0..i {
+                    dense[j][i] = dense[i][j];
+                }
+            }
+            if dense.determinant().abs() < 1e-3 {
+                continue;
+            }
+            let symmetric = Symmetric::try_from(&dense).unwrap();
+            assert_abs_diff_eq!(
+                symmetric.determinant(),
+                dense.determinant(),
+                epsilon = THRESHOLD
+            );
+            assert_matrix_eq(
+                &Matrix::from(symmetric.inverse().unwrap()),
+                &dense.inverse().unwrap(),
+            );
+        }
+    }
+}
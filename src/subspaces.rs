@@ -0,0 +1,162 @@
+use crate::field::Field;
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+
+// Bases come from the reduced row echelon form, so they are exact for exact
+// fields and follow `rank` in what counts as zero for floats.
+
+/// One null space vector per column missing from `pivots`: the free variable
+/// set to one, the others to zero, the pivots solved for from `reduced`,
+/// which reads entry `(row, col)` of a reduced row echelon form.
+pub(crate) fn null_space_basis<K: Field, const N: usize>(
+    reduced: impl Fn(usize, usize) -> K,
+    pivots: &[usize],
+) -> Vec<Vector<K, N>> {
+    (0..N)
+        .filter(|col| !pivots.contains(col))
+        .map(|free| {
+            let mut basis = Vector::new([K::zero(); N]);
+            basis[free] = K::one();
+            for (row, &pivot) in pivots.iter().enumerate() {
+                basis[pivot] = -reduced(row, free);
+            }
+            basis
+        })
+        .collect()
+}
+
+impl<K: Field, const M: usize, const N: usize> Matrix<K, M, N> {
+    /// Columns holding a leading one in `row_echelon`, in increasing order.
+    pub fn pivot_columns(&self) -> Vec<usize> {
+        self.row_echelon()
+            .iter()
+            .filter_map(|row| row.iter().position(|&x| x != K::zero()))
+            .collect()
+    }
+
+    /// Dimension of the null space, `N - rank`.
+    pub fn nullity(&self) -> usize {
+        N - self.rank()
+    }
+
+    /// Basis of `{x : A·x = 0}`, one vector per free column: the free
+    /// variable set to one, the others to zero, the pivots solved for.
+    pub fn null_space(&self) -> Vec<Vector<K, N>> {
+        let reduced = self.row_echelon();
+        null_space_basis(|row, col| reduced[row][col], &self.pivot_columns())
+    }
+
+    /// Basis of the image: the columns of `A` at the pivot columns.
+    pub fn column_space(&self) -> Vec<Vector<K, M>> {
+        self.pivot_columns()
+            .into_iter()
+            .map(|col| Vector::new(std::array::from_fn(|row| self[row][col])))
+            .collect()
+    }
+
+    /// Basis of the span of the rows: the non-zero rows of `row_echelon`.
+    pub fn row_space(&self) -> Vec<Vector<K, N>> {
+        self.row_echelon()
+            .iter()
+            .filter(|row| row.iter().any(|&x| x != K::zero()))
+            .map(|&row| Vector::new(row))
+            .collect()
+    }
+
+    /// Basis of `{y : yᵀ·A = 0}`, the null space of `Aᵀ`.
+    pub fn left_null_space(&self) -> Vec<Vector<K, M>> {
+        self.transpose().null_space()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rational64;
+    use rand::prelude::*;
+
+    const NB_TESTCASE_MATRICES: usize = 100;
+
+    // Rationals, since rounding residue in a float echelon form can change
+    // the rank between `A` and `Aᵀ`.
+    fn generate_random_matrix<const M: usize, const N: usize>() -> Matrix<Rational64, M, N> {
+        let mut rng = rand::thread_rng();
+        let mut data = [[0; N]; M];
+        for row in &mut data {
+            for elem in row {
+                *elem = rng.gen_range(-10..10);
+            }
+        }
+        rational(data)
+    }
+
+    fn rational<const M: usize, const N: usize>(data: [[i64; N]; M]) -> Matrix<Rational64, M, N> {
+        Matrix::new(data.map(|row| row.map(Rational64::from_integer)))
+    }
+
+    #[test]
+    fn subspaces_exact() {
+        let a = rational([[1, 2, 0, 3], [2, 4, 1, 8], [3, 6, 1, 11]]);
+        assert_eq!(a.pivot_columns(), vec![0, 2]);
+        assert_eq!(a.rank(), 2);
+        assert_eq!(a.nullity(), 2);
+
+        let r = Rational64::from_integer;
+        assert_eq!(
+            a.null_space(),
+            vec![
+                Vector::new([r(-2), r(1), r(0), r(0)]),
+                Vector::new([r(-3), r(0), r(-2), r(1)]),
+            ]
+        );
+        assert_eq!(
+            a.column_space(),
+            vec![
+                Vector::new([r(1), r(2), r(3)]),
+                Vector::new([r(0), r(1), r(1)])
+            ]
+        );
+        assert_eq!(
+            a.row_space(),
+            vec![
+                Vector::new([r(1), r(2), r(0), r(3)]),
+                Vector::new([r(0), r(0), r(1), r(2)]),
+            ]
+        );
+        assert_eq!(a.left_null_space(), vec![Vector::new([r(-1), r(-1), r(1)])]);
+
+        for v in a.null_space() {
+            assert_eq!(a.mul_vec(&v), Vector::new([r(0); 3]));
+        }
+        for y in a.left_null_space() {
+            assert_eq!(a.transpose().mul_vec(&y), Vector::new([r(0); 4]));
+        }
+    }
+
+    #[test]
+    fn subspaces_rank_nullity() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let a = generate_random_matrix::<5, 2>().mul_mat(&generate_random_matrix::<2, 4>());
+            let rank = a.rank();
+            assert_eq!(rank + a.nullity(), 4);
+            assert_eq!(a.pivot_columns().len(), rank);
+            assert_eq!(a.column_space().len(), rank);
+            assert_eq!(a.row_space().len(), rank);
+            assert_eq!(a.null_space().len(), a.nullity());
+            assert_eq!(a.left_null_space().len(), 5 - rank);
+
+            let zero = Rational64::from_integer(0);
+            for v in a.null_space() {
+                assert_eq!(a.mul_vec(&v), Vector::new([zero; 5]));
+            }
+            for y in a.left_null_space() {
+                assert_eq!(a.transpose().mul_vec(&y), Vector::new([zero; 4]));
+            }
+        }
+
+        let zero = Matrix::new([[0.; 3]; 2]);
+        assert_eq!(zero.nullity(), 3);
+        assert!(zero.column_space().is_empty());
+        assert_eq!(zero.null_space().len(), 3);
+    }
+}
@@ -0,0 +1,461 @@
+use crate::field::Real;
+use crate::matrix::Matrix;
+use crate::structured::Diagonal;
+
+use std::cmp::Ordering;
+
+const MAX_SWEEPS: usize = 60;
+
+/// `A = U·Σ·Vᵀ` with orthogonal `U`, `V` and the singular values sorted in
+/// decreasing order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SVD<K, const M: usize, const N: usize> {
+    u: Matrix<K, M, M>,
+    singular_values: Vec<K>,
+    v_t: Matrix<K, N, N>,
+}
+
+/// One-sided Jacobi (Hestenes) on a matrix with at least as many rows as
+/// columns: rotate column pairs of `A·V` until they are orthogonal, at
+/// which point their norms are the singular values.
+fn jacobi<K: Real, const R: usize, const C: usize>(
+    matrix: &Matrix<K, R, C>,
+    vectors: bool,
+) -> (Matrix<K, R, R>, Vec<K>, Matrix<K, C, C>) {
+    debug_assert!(R >= C);
+    let epsilon = K::from_f64(f64::EPSILON);
+    let dot = |a: &[K; R], b: &[K; R]| a.iter().zip(b).fold(K::zero(), |acc, (&x, &y)| acc + x * y);
+
+    // Rows of `columns` are the columns of `A·V`, rows of `v_t` those of `V`.
+    let mut columns = matrix.transpose();
+    let mut v_t = Matrix::<K, C, C>::identity();
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..C {
+            for q in (p + 1)..C {
+                let alpha = dot(&columns[p], &columns[p]);
+                let beta = dot(&columns[q], &columns[q]);
+                let gamma = dot(&columns[p], &columns[q]);
+                if gamma.abs() <= epsilon * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (gamma + gamma);
+                let t = K::one() / (zeta.abs() + (K::one() + zeta * zeta).sqrt());
+                let t = if zeta < K::zero() { -t } else { t };
+                let c = K::one() / (K::one() + t * t).sqrt();
+                let s = c * t;
+
+                for i in 0..R {
+                    let (a, b) = (columns[p][i], columns[q][i]);
+                    columns[p][i] = c * a - s * b;
+                    columns[q][i] = s * a + c * b;
+                }
+                if vectors {
+                    for i in 0..C {
+                        let (a, b) = (v_t[p][i], v_t[q][i]);
+                        v_t[p][i] = c * a - s * b;
+                        v_t[q][i] = s * a + c * b;
+                    }
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let norms: Vec<K> = columns
+        .iter()
+        .map(|column| dot(column, column).sqrt())
+        .collect();
+    let mut order: Vec<usize> = (0..C).collect();
+    order.sort_by(|&i, &j| norms[j].partial_cmp(&norms[i]).unwrap_or(Ordering::Equal));
+    let singular_values: Vec<K> = order.iter().map(|&i| norms[i]).collect();
+    if !vectors {
+        return (Matrix::identity(), singular_values, v_t);
+    }
+
+    let v_t = Matrix::new(std::array::from_fn(|i| v_t[order[i]]));
+
+    // Columns for negligible singular values, and the last `R - C`, are
+    // completed to an orthonormal basis from the standard basis.
+    let cutoff =
+        singular_values.first().copied().unwrap_or(K::zero()) * epsilon * K::from_f64(R as f64);
+    let mut u = [[K::zero(); R]; R];
+    let mut filled = 0;
+    for (&i, &sigma) in order.iter().zip(&singular_values) {
+        if sigma <= cutoff {
+            break;
+        }
+        u[filled] = columns[i].map(|x| x / sigma);
+        filled += 1;
+    }
+    for candidate in 0..R {
+        if filled == R {
+            break;
+        }
+        let mut column = [K::zero(); R];
+        column[candidate] = K::one();
+        for _ in 0..2 {
+            for basis in &u[..filled] {
+                let projection = dot(basis, &column);
+                for (x, &b) in column.iter_mut().zip(basis) {
+                    *x -= projection * b;
+                }
+            }
+        }
+        let norm = dot(&column, &column).sqrt();
+        if norm > K::from_f64(0.5) {
+            u[filled] = column.map(|x| x / norm);
+            filled += 1;
+        }
+    }
+
+    (Matrix::new(u).transpose(), singular_values, v_t)
+}
+
+impl<K: Real, const M: usize, const N: usize> SVD<K, M, N> {
+    pub fn new(matrix: &Matrix<K, M, N>) -> Self {
+        if M >= N {
+            let (u, singular_values, v_t) = jacobi(matrix, true);
+            SVD {
+                u,
+                singular_values,
+                v_t,
+            }
+        } else {
+            // `Aᵀ = U'·Σ·V'ᵀ` gives `A = V'·Σ·U'ᵀ`.
+            let (u, singular_values, v_t) = jacobi(&matrix.transpose(), true);
+            SVD {
+                u: v_t.transpose(),
+                singular_values,
+                v_t: u.transpose(),
+            }
+        }
+    }
+
+    pub fn u(&self) -> &Matrix<K, M, M> {
+        &self.u
+    }
+
+    /// The `M × N` matrix with the singular values on its diagonal.
+    pub fn sigma(&self) -> Matrix<K, M, N> {
+        let mut result = Matrix::new([[K::zero(); N]; M]);
+        for (i, &sigma) in self.singular_values.iter().enumerate() {
+            result[i][i] = sigma;
+        }
+        result
+    }
+
+    pub fn v_t(&self) -> &Matrix<K, N, N> {
+        &self.v_t
+    }
+
+    /// The `min(M, N)` singular values, largest first.
+    pub fn singular_values(&self) -> &[K] {
+        &self.singular_values
+    }
+
+    /// Thin factors `U₁ (M × P)`, `Σ₁ (P × P)` and `V₁ᵀ (P × N)` with
+    /// `P = min(M, N)`.
+    pub fn thin<const P: usize>(&self) -> (Matrix<K, M, P>, Diagonal<K, P>, Matrix<K, P, N>) {
+        const {
+            assert!(
+                P == if M < N { M } else { N },
+                "thin SVD has min(M, N) singular values"
+            )
+        };
+        let u = Matrix::new(std::array::from_fn(|i| {
+            std::array::from_fn(|j| self.u[i][j])
+        }));
+        let sigma = Diagonal::new(std::array::from_fn(|i| self.singular_values[i]));
+        let v_t = Matrix::new(std::array::from_fn(|i| self.v_t[i]));
+        (u, sigma, v_t)
+    }
+
+    /// Number of singular values above `tolerance`.
+    pub fn rank(&self, tolerance: K) -> usize {
+        self.singular_values
+            .iter()
+            .filter(|&&sigma| sigma > tolerance)
+            .count()
+    }
+
+    /// Largest singular value.
+    pub fn norm_2(&self) -> K {
+        self.singular_values.first().copied().unwrap_or(K::zero())
+    }
+
+    /// `σ_max / σ_min`, infinite for floats when the matrix is rank deficient.
+    pub fn condition_number(&self) -> K {
+        let smallest = self.singular_values.last().copied().unwrap_or(K::one());
+        self.norm_2() / smallest
+    }
+
+    /// Best approximation of rank at most `rank`, in both the 2-norm and the
+    /// Frobenius norm.
+    pub fn low_rank_approximation(&self, rank: usize) -> Matrix<K, M, N> {
+        let mut result = Matrix::new([[K::zero(); N]; M]);
+        for (k, &sigma) in self.singular_values.iter().enumerate().take(rank) {
+            for i in 0..M {
+                for j in 0..N {
+                    result[i][j] += self.u[i][k] * sigma * self.v_t[k][j];
+                }
+            }
+        }
+        result
+    }
+
+    /// Default pseudo-inverse cutoff, `max(M, N)·ε·σ_max` as in MATLAB's
+    /// `pinv`.
+    pub fn default_cutoff(&self) -> K {
+        K::from_f64(M.max(N) as f64 * f64::EPSILON) * self.norm_2()
+    }
+
+    /// `V·Σ⁺·Uᵀ`, where `Σ⁺` inverts the singular values above `cutoff` and
+    /// zeroes the others.
+    pub fn pseudo_inverse(&self, cutoff: K) -> Matrix<K, N, M> {
+        let mut result = Matrix::new([[K::zero(); M]; N]);
+        for (k, &sigma) in self.singular_values.iter().enumerate() {
+            if sigma <= cutoff {
+                break;
+            }
+            for i in 0..N {
+                for j in 0..M {
+                    result[i][j] += self.v_t[k][i] * self.u[j][k] / sigma;
+                }
+            }
+        }
+        result
+    }
+}
+
+impl<K: Real, const M: usize, const N: usize> Matrix<K, M, N> {
+    /// Moore–Penrose pseudo-inverse with the default cutoff: the inverse for
+    /// invertible matrices, and otherwise the map giving least-squares,
+    /// least-norm solutions of `A·x = b`.
+    pub fn pseudo_inverse(&self) -> Matrix<K, N, M> {
+        let svd = self.svd();
+        svd.pseudo_inverse(svd.default_cutoff())
+    }
+
+    /// Pseudo-inverse treating singular values up to `cutoff` as zero.
+    pub fn pseudo_inverse_with_cutoff(&self, cutoff: K) -> Matrix<K, N, M> {
+        self.svd().pseudo_inverse(cutoff)
+    }
+
+    pub fn svd(&self) -> SVD<K, M, N> {
+        SVD::new(self)
+    }
+
+    /// The singular values alone, largest first, skipping the work on `U`
+    /// and `V`.
+    pub fn singular_values(&self) -> Vec<K> {
+        if M >= N {
+            jacobi(self, false).1
+        } else {
+            jacobi(&self.transpose(), false).1
+        }
+    }
+
+    pub fn norm_2(&self) -> K {
+        self.singular_values().first().copied().unwrap_or(K::zero())
+    }
+
+    pub fn condition_number(&self) -> K {
+        let singular_values = self.singular_values();
+        match (singular_values.first(), singular_values.last()) {
+            (Some(&largest), Some(&smallest)) => largest / smallest,
+            _ => K::one(),
+        }
+    }
+
+    /// Number of singular values above `tolerance`; unlike `rank`, not
+    /// thrown off by rounding in the elimination.
+    pub fn numerical_rank(&self, tolerance: K) -> usize {
+        self.singular_values()
+            .iter()
+            .filter(|&&sigma| sigma > tolerance)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use nalgebra::DMatrix;
+    use rand::prelude::*;
+
+    const NB_TESTCASE_MATRICES: usize = 100;
+    const THRESHOLD: f64 = 1e-8;
+
+    fn generate_random_matrix<const M: usize, const N: usize>() -> Matrix<f64, M, N> {
+        let mut rng = rand::thread_rng();
+        let mut data = [[0.0; N]; M];
+        for row in &mut data {
+            for elem in row {
+                *elem = rng.gen_range(-1.0..1.0);
+            }
+        }
+        Matrix::new(data)
+    }
+
+    fn assert_matrix_eq<const M: usize, const N: usize>(
+        matrix: &Matrix<f64, M, N>,
+        expected: &Matrix<f64, M, N>,
+    ) {
+        for i in 0..M {
+            for j in 0..N {
+                assert_abs_diff_eq!(matrix[i][j], expected[i][j], epsilon = THRESHOLD);
+            }
+        }
+    }
+
+    fn test_svd<const M: usize, const N: usize, const P: usize>(a: &Matrix<f64, M, N>) {
+        let svd = a.svd();
+        let (u, v_t) = (svd.u(), svd.v_t());
+        assert_matrix_eq(&u.mul_mat(&svd.sigma()).mul_mat(v_t), a);
+        assert_matrix_eq(&u.transpose().mul_mat(u), &Matrix::identity());
+        assert_matrix_eq(&v_t.mul_mat(&v_t.transpose()), &Matrix::identity());
+
+        let (thin_u, thin_sigma, thin_v_t) = svd.thin::<P>();
+        assert_matrix_eq(
+            &thin_u.mul_mat(&Matrix::from(thin_sigma)).mul_mat(&thin_v_t),
+            a,
+        );
+
+        let mut expected = DMatrix::from_fn(M, N, |i, j| a[i][j])
+            .singular_values()
+            .as_slice()
+            .to_vec();
+        expected.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        let values = a.singular_values();
+        for i in 0..P {
+            assert_abs_diff_eq!(svd.singular_values()[i], expected[i], epsilon = THRESHOLD);
+            assert_abs_diff_eq!(values[i], expected[i], epsilon = THRESHOLD);
+        }
+        assert_abs_diff_eq!(a.norm_2(), expected[0], epsilon = THRESHOLD);
+    }
+
+    #[test]
+    fn svd_square() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let a = generate_random_matrix::<4, 4>();
+            test_svd::<4, 4, 4>(&a);
+            let condition = a.condition_number();
+            let inverse_norm = a.inverse().unwrap().norm_2();
+            assert_abs_diff_eq!(condition / (a.norm_2() * inverse_norm), 1., epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn svd_tall() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            test_svd::<6, 3, 3>(&generate_random_matrix());
+        }
+    }
+
+    #[test]
+    fn svd_wide() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            test_svd::<3, 5, 3>(&generate_random_matrix());
+        }
+    }
+
+    #[test]
+    fn svd_rank_deficient() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let a = generate_random_matrix::<5, 2>().mul_mat(&generate_random_matrix::<2, 4>());
+            test_svd::<5, 4, 4>(&a);
+            assert_eq!(a.svd().rank(1e-10), 2);
+            assert_eq!(a.numerical_rank(1e-10), 2);
+            assert_matrix_eq(&a.svd().low_rank_approximation(2), &a);
+        }
+
+        let zero = Matrix::new([[0.; 3]; 2]);
+        test_svd::<2, 3, 2>(&zero);
+        assert_eq!(zero.numerical_rank(1e-10), 0);
+
+        // Row echelon rank is exact only in exact arithmetic.
+        let a = Matrix::new([[1., 1.], [1., 1. + 1e-14]]);
+        assert_eq!(a.rank(), 2);
+        assert_eq!(a.numerical_rank(1e-10), 1);
+    }
+
+    fn test_pseudo_inverse<const M: usize, const N: usize>(a: &Matrix<f64, M, N>) {
+        let pinv = a.pseudo_inverse();
+        // The four Penrose conditions.
+        let (apa, pap) = (a.mul_mat(&pinv).mul_mat(a), pinv.mul_mat(a).mul_mat(&pinv));
+        let (ap, pa) = (a.mul_mat(&pinv), pinv.mul_mat(a));
+        assert_matrix_eq(&apa, a);
+        assert_matrix_eq(&pap, &pinv);
+        assert_matrix_eq(&ap.transpose(), &ap);
+        assert_matrix_eq(&pa.transpose(), &pa);
+
+        // nalgebra's own pseudo-inverse is only reliable at full rank.
+        if a.numerical_rank(1e-10) < M.min(N) {
+            return;
+        }
+        let expected = DMatrix::from_fn(M, N, |i, j| a[i][j])
+            .pseudo_inverse(1e-10)
+            .unwrap();
+        for i in 0..N {
+            for j in 0..M {
+                assert_abs_diff_eq!(pinv[i][j], expected[(i, j)], epsilon = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn svd_pseudo_inverse() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            test_pseudo_inverse(&generate_random_matrix::<6, 3>());
+            test_pseudo_inverse(&generate_random_matrix::<3, 5>());
+            let a = generate_random_matrix::<5, 2>().mul_mat(&generate_random_matrix::<2, 4>());
+            test_pseudo_inverse(&a);
+
+            let square = generate_random_matrix::<4, 4>();
+            if square.determinant().abs() > 1e-2 {
+                let inverse = square.inverse().unwrap();
+                let pinv = square.pseudo_inverse();
+                for i in 0..4 {
+                    for j in 0..4 {
+                        assert_abs_diff_eq!(pinv[i][j], inverse[i][j], epsilon = 1e-6);
+                    }
+                }
+            }
+        }
+
+        // Least-norm solution of `x + y = 2`.
+        let underdetermined = Matrix::new([[1., 1.]]);
+        let x = underdetermined.pseudo_inverse().mul_vec(&[2.][..]);
+        assert_abs_diff_eq!(x[0], 1., epsilon = THRESHOLD);
+        assert_abs_diff_eq!(x[1], 1., epsilon = THRESHOLD);
+
+        let nearly_singular = Matrix::new([[1., 0.], [0., 1e-9]]);
+        assert_abs_diff_eq!(nearly_singular.pseudo_inverse()[1][1], 1e9, epsilon = 1e-3);
+        let truncated = nearly_singular.pseudo_inverse_with_cutoff(1e-6);
+        assert_matrix_eq(&truncated, &Matrix::new([[1., 0.], [0., 0.]]));
+        assert_eq!(
+            Matrix::new([[0.; 3]; 2]).pseudo_inverse(),
+            Matrix::new([[0.; 2]; 3])
+        );
+    }
+
+    #[test]
+    fn svd_diagonal() {
+        let a = Matrix::new([[3., 0.], [0., -4.], [0., 0.]]);
+        let svd = a.svd();
+        assert_eq!(svd.singular_values(), &[4., 3.]);
+        assert_eq!(svd.norm_2(), 4.);
+        assert_abs_diff_eq!(svd.condition_number(), 4. / 3., epsilon = THRESHOLD);
+        assert_matrix_eq(
+            &svd.low_rank_approximation(1),
+            &Matrix::new([[0., 0.], [0., -4.], [0., 0.]]),
+        );
+    }
+}
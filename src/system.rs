@@ -0,0 +1,212 @@
+use crate::dmatrix::DMatrix;
+use crate::field::{Field, Ring};
+use crate::matrix::Matrix;
+use crate::subspaces::null_space_basis;
+use crate::vector::Vector;
+
+use std::fmt::{Display, Formatter};
+
+/// Classification of `A·x = b`, read off the reduced row echelon form of
+/// the augmented matrix `[A | b]`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Solution<K, const N: usize> {
+    Unique(Vector<K, N>),
+    /// Equation `row` of the system, counted as in `A` and `b`, reduces to
+    /// `0 = 1` against the equations eliminated before it.
+    Inconsistent {
+        row: usize,
+    },
+    /// Every solution is `particular + Σ x_f·null_space[i]`, with `x_f` the
+    /// free variable `free_variables[i]` taking any value.
+    Infinite {
+        particular: Vector<K, N>,
+        free_variables: Vec<usize>,
+        null_space: Vec<Vector<K, N>>,
+    },
+}
+
+pub fn solve<K: Field, const M: usize, const N: usize>(
+    a: &Matrix<K, M, N>,
+    b: &Vector<K, M>,
+) -> Solution<K, N> {
+    let data = (0..M)
+        .flat_map(|i| a[i].iter().copied().chain(std::iter::once(b[i])))
+        .collect();
+    let (reduced, permutation) = DMatrix::new(M, N + 1, data).row_echelon_permuted();
+
+    let mut pivots = Vec::new();
+    for row in 0..M {
+        match reduced[row].iter().position(|&x| x != K::zero()) {
+            Some(col) if col == N => {
+                return Solution::Inconsistent {
+                    row: permutation[row],
+                }
+            }
+            Some(col) => pivots.push(col),
+            None => break,
+        }
+    }
+
+    let mut particular = Vector::new([K::zero(); N]);
+    for (row, &col) in pivots.iter().enumerate() {
+        particular[col] = reduced[row][N];
+    }
+    if pivots.len() == N {
+        return Solution::Unique(particular);
+    }
+
+    let free_variables: Vec<usize> = (0..N).filter(|col| !pivots.contains(col)).collect();
+    let null_space = null_space_basis(|row, col| reduced[row][col], &pivots);
+    Solution::Infinite {
+        particular,
+        free_variables,
+        null_space,
+    }
+}
+
+/// One line per variable, e.g. `x0 = 2.000 + -1.000·x1` then `x1 free`.
+impl<K: Ring + Display, const N: usize> Display for Solution<K, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Solution::Unique(x) => {
+                for (i, value) in x.iter().enumerate() {
+                    writeln!(f, "x{} = {:.3}", i, value)?;
+                }
+            }
+            Solution::Inconsistent { row } => {
+                writeln!(f, "No solution: equation {} reduces to 0 = 1", row)?;
+            }
+            Solution::Infinite {
+                particular,
+                free_variables,
+                null_space,
+            } => {
+                for i in 0..N {
+                    if free_variables.contains(&i) {
+                        writeln!(f, "x{} free", i)?;
+                        continue;
+                    }
+                    write!(f, "x{} = {:.3}", i, particular[i])?;
+                    for (&free, basis) in free_variables.iter().zip(null_space) {
+                        if basis[i] != K::zero() {
+                            write!(f, " + {:.3}·x{}", basis[i], free)?;
+                        }
+                    }
+                    writeln!(f)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rational64;
+    use approx::assert_abs_diff_eq;
+    use rand::prelude::*;
+
+    const NB_TESTCASE_MATRICES: usize = 100;
+    const THRESHOLD: f64 = 1e-8;
+
+    fn generate_random_matrix<const M: usize, const N: usize>() -> Matrix<f64, M, N> {
+        let mut rng = rand::thread_rng();
+        let mut data = [[0.0; N]; M];
+        for row in &mut data {
+            for elem in row {
+                *elem = rng.gen_range(-1.0..1.0);
+            }
+        }
+        Matrix::new(data)
+    }
+
+    fn rational<const M: usize, const N: usize>(data: [[i64; N]; M]) -> Matrix<Rational64, M, N> {
+        Matrix::new(data.map(|row| row.map(Rational64::from_integer)))
+    }
+
+    #[test]
+    fn solve_unique() {
+        for _ in 0..NB_TESTCASE_MATRICES {
+            let a = generate_random_matrix::<4, 4>();
+            let b = Vector::new(generate_random_matrix::<1, 4>()[0]);
+            if a.determinant().abs() < 1e-3 {
+                continue;
+            }
+            let Solution::Unique(x) = solve(&a, &b) else {
+                panic!("expected a unique solution");
+            };
+            let expected = a.lu().solve(&b).unwrap();
+            for i in 0..4 {
+                assert_abs_diff_eq!(x[i], expected[i], epsilon = 1e-6);
+                assert_abs_diff_eq!(a.mul_vec(&x)[i], b[i], epsilon = THRESHOLD);
+            }
+        }
+
+        let a = Matrix::new([[2., 1.], [1., 3.], [1., -2.]]);
+        let solution = solve(&a, &Vector::new([3., 4., -1.]));
+        assert_eq!(solution, Solution::Unique(Vector::new([1., 1.])));
+        assert_eq!(solution.to_string(), "x0 = 1.000\nx1 = 1.000\n");
+    }
+
+    #[test]
+    fn solve_inconsistent() {
+        // Pivoting moves the second equation up; the first one is reported.
+        let a = Matrix::new([[1., 2.], [2., 4.]]);
+        let solution = solve(&a, &Vector::new([1., 3.]));
+        assert_eq!(solution, Solution::Inconsistent { row: 0 });
+        assert_eq!(
+            solution.to_string(),
+            "No solution: equation 0 reduces to 0 = 1\n"
+        );
+
+        let zero = Matrix::new([[0.; 3]; 2]);
+        assert_eq!(
+            solve(&zero, &Vector::new([0., 2.])),
+            Solution::Inconsistent { row: 1 }
+        );
+
+        let a = Matrix::new([[1., 1.], [1., 1.], [0., 1.]]);
+        assert_eq!(
+            solve(&a, &Vector::new([1., 1., 1.])),
+            Solution::Unique(Vector::new([0., 1.]))
+        );
+        assert_eq!(
+            solve(&a, &Vector::new([1., 2., 1.])),
+            Solution::Inconsistent { row: 1 }
+        );
+    }
+
+    #[test]
+    fn solve_infinite() {
+        // The system of ex10, whose second variable is free.
+        let mut a = rational([[8, 5, -2, 4], [4, 0, 20, 4], [8, 5, 1, 4]]);
+        a[1][1] = Rational64::new(5, 2);
+        let b = Vector::new([28, -4, 17].map(Rational64::from_integer));
+
+        let Solution::Infinite {
+            particular,
+            free_variables,
+            null_space,
+        } = solve(&a, &b)
+        else {
+            panic!("expected infinitely many solutions");
+        };
+        assert_eq!(free_variables, vec![1]);
+        assert_eq!(null_space, a.null_space());
+        assert_eq!(a.mul_vec(&particular), b);
+
+        let t = Rational64::new(-7, 3);
+        let mut x = particular;
+        for (xi, &vi) in x.iter_mut().zip(null_space[0].iter()) {
+            *xi += t * vi;
+        }
+        assert_eq!(a.mul_vec(&x), b);
+
+        let floats = Matrix::new([[1., 2., 0.], [0., 0., 1.]]);
+        assert_eq!(
+            solve(&floats, &Vector::new([3., 4.])).to_string(),
+            "x0 = 3.000 + -2.000·x1\nx1 free\nx2 = 4.000\n"
+        );
+    }
+}
@@ -1,4 +1,5 @@
-use crate::field::*;
+use crate::field::{Field, RealField, Ring};
+use crate::view::VectorAccess;
 
 use std::fmt::{Display, Formatter, Result};
 use std::ops::{
@@ -10,7 +11,7 @@ pub struct Vector<K, const N: usize> {
     data: [K; N],
 }
 
-impl<K: Field + Display, const N: usize> Display for Vector<K, N> {
+impl<K: Ring + Display, const N: usize> Display for Vector<K, N> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(f, "[")?;
         for (index, item) in self.iter().enumerate() {
@@ -24,7 +25,7 @@ impl<K: Field + Display, const N: usize> Display for Vector<K, N> {
     }
 }
 
-impl<K: Field, const N: usize> Deref for Vector<K, N> {
+impl<K: Ring, const N: usize> Deref for Vector<K, N> {
     type Target = [K];
 
     fn deref(&self) -> &Self::Target {
@@ -32,7 +33,7 @@ impl<K: Field, const N: usize> Deref for Vector<K, N> {
     }
 }
 
-impl<K: Field, const N: usize> DerefMut for Vector<K, N> {
+impl<K: Ring, const N: usize> DerefMut for Vector<K, N> {
     fn deref_mut(&mut self) -> &mut [K] {
         &mut self.data
     }
@@ -47,7 +48,7 @@ impl<K, const N: usize> IntoIterator for Vector<K, N> {
     }
 }
 
-impl<K: Field, const N: usize> Add<Vector<K, N>> for Vector<K, N> {
+impl<K: Ring, const N: usize> Add<Vector<K, N>> for Vector<K, N> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
@@ -58,7 +59,7 @@ impl<K: Field, const N: usize> Add<Vector<K, N>> for Vector<K, N> {
     }
 }
 
-impl<K: Field, const N: usize> Add<K> for Vector<K, N> {
+impl<K: Ring, const N: usize> Add<K> for Vector<K, N> {
     type Output = Self;
 
     fn add(self, scalar: K) -> Self::Output {
@@ -68,19 +69,19 @@ impl<K: Field, const N: usize> Add<K> for Vector<K, N> {
     }
 }
 
-impl<K: Field, const N: usize> AddAssign<Vector<K, N>> for Vector<K, N> {
+impl<K: Ring, const N: usize> AddAssign<Vector<K, N>> for Vector<K, N> {
     fn add_assign(&mut self, rhs: Vector<K, N>) {
         self.operate(&rhs, |a, b| a + b);
     }
 }
 
-impl<K: Field, const N: usize> AddAssign<K> for Vector<K, N> {
+impl<K: Ring, const N: usize> AddAssign<K> for Vector<K, N> {
     fn add_assign(&mut self, scalar: K) {
         self.operate_scalar(scalar, |a, b| a + b);
     }
 }
 
-impl<K: Field, const N: usize> Sub<Vector<K, N>> for Vector<K, N> {
+impl<K: Ring, const N: usize> Sub<Vector<K, N>> for Vector<K, N> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
@@ -91,7 +92,7 @@ impl<K: Field, const N: usize> Sub<Vector<K, N>> for Vector<K, N> {
     }
 }
 
-impl<K: Field, const N: usize> Sub<K> for Vector<K, N> {
+impl<K: Ring, const N: usize> Sub<K> for Vector<K, N> {
     type Output = Self;
 
     fn sub(self, scalar: K) -> Self::Output {
@@ -101,19 +102,19 @@ impl<K: Field, const N: usize> Sub<K> for Vector<K, N> {
     }
 }
 
-impl<K: Field, const N: usize> SubAssign<Vector<K, N>> for Vector<K, N> {
+impl<K: Ring, const N: usize> SubAssign<Vector<K, N>> for Vector<K, N> {
     fn sub_assign(&mut self, rhs: Vector<K, N>) {
         self.operate(&rhs, |a, b| a - b);
     }
 }
 
-impl<K: Field, const N: usize> SubAssign<K> for Vector<K, N> {
+impl<K: Ring, const N: usize> SubAssign<K> for Vector<K, N> {
     fn sub_assign(&mut self, scalar: K) {
         self.operate_scalar(scalar, |a, b| a - b);
     }
 }
 
-impl<K: Field, const N: usize> Mul<Vector<K, N>> for Vector<K, N> {
+impl<K: Ring, const N: usize> Mul<Vector<K, N>> for Vector<K, N> {
     type Output = K;
 
     fn mul(self, rhs: Vector<K, N>) -> K {
@@ -121,7 +122,7 @@ impl<K: Field, const N: usize> Mul<Vector<K, N>> for Vector<K, N> {
     }
 }
 
-impl<K: Field, const N: usize> Mul<K> for Vector<K, N> {
+impl<K: Ring, const N: usize> Mul<K> for Vector<K, N> {
     type Output = Self;
 
     fn mul(self, scalar: K) -> Self::Output {
@@ -131,7 +132,7 @@ impl<K: Field, const N: usize> Mul<K> for Vector<K, N> {
     }
 }
 
-impl<K: Field, const N: usize> MulAssign<K> for Vector<K, N> {
+impl<K: Ring, const N: usize> MulAssign<K> for Vector<K, N> {
     fn mul_assign(&mut self, scalar: K) {
         self.operate_scalar(scalar, |a, b| a * b);
     }
@@ -157,7 +158,7 @@ impl<K: Field, const N: usize> DivAssign<K> for Vector<K, N> {
     }
 }
 
-impl<K: Field, const N: usize> Neg for Vector<K, N> {
+impl<K: Ring, const N: usize> Neg for Vector<K, N> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
@@ -167,7 +168,7 @@ impl<K: Field, const N: usize> Neg for Vector<K, N> {
     }
 }
 
-impl<K: Field, const N: usize> Vector<K, N> {
+impl<K: Ring, const N: usize> Vector<K, N> {
     fn operate<F: Fn(K, K) -> K>(&mut self, v: &Vector<K, N>, op: F) {
         self.iter_mut()
             .zip(&v.data)
@@ -182,6 +183,25 @@ impl<K: Field, const N: usize> Vector<K, N> {
         Vector { data }
     }
 
+    pub fn add(&mut self, v: &Vector<K, N>) {
+        self.operate(v, |a, b| a + b);
+    }
+
+    pub fn sub(&mut self, v: &Vector<K, N>) {
+        self.operate(v, |a, b| a - b);
+    }
+
+    pub fn scl(&mut self, scalar: K) {
+        self.operate_scalar(scalar, |a, b| a * b);
+    }
+
+    pub fn dot<V: VectorAccess<K> + ?Sized>(&self, v: &V) -> K {
+        assert_eq!(N, v.dim(), "Vector dimensions must match");
+        self.iter()
+            .enumerate()
+            .fold(K::zero(), |acc, (i, &x)| acc + x.conjugate() * v.at(i))
+    }
+
     pub fn linear_combination(u: &[Vector<K, N>], coefs: &[K]) -> Vector<K, N> {
         assert_eq!(
             u.len(),
@@ -192,22 +212,57 @@ impl<K: Field, const N: usize> Vector<K, N> {
         let mut result = [K::zero(); N];
         for (vector, coef) in u.iter().zip(coefs.iter()) {
             for (a, b) in result.iter_mut().zip(&vector.data) {
-                *a = *a + *coef * *b;
+                *a += *coef * *b;
             }
         }
 
         Vector { data: result }
     }
+}
+
+impl<K: Field, const N: usize> Vector<K, N> {
+    pub fn inv_scl(&mut self, scalar: K) {
+        self.operate_scalar(scalar, |a, b| a / b);
+    }
+}
 
-    pub fn dot(&self, v: &Vector<K, N>) -> K {
+impl<K: RealField, const N: usize> Vector<K, N> {
+    pub fn norm_1(&self) -> f64 {
         self.iter()
-            .zip(&v.data)
-            .fold(K::zero(), |acc, (&x, &y)| acc + x * y)
+            .fold(f64::default(), |acc, &x| acc + x.modulus())
+    }
+
+    pub fn norm(&self) -> f64 {
+        self.dot(self).modulus().sqrt()
+    }
+
+    pub fn norm_inf(&self) -> f64 {
+        self.iter()
+            .fold(f64::NEG_INFINITY, |max, &x| x.modulus().max(max))
     }
 }
 
-impl<K: Field, const N: usize> From<[K; N]> for Vector<K, N> {
+impl<K: Ring, const N: usize> From<[K; N]> for Vector<K, N> {
     fn from(array: [K; N]) -> Self {
         Vector::new(array)
     }
 }
+
+pub fn angle_cos<K: RealField, const N: usize>(u: &Vector<K, N>, v: &Vector<K, N>) -> f32 {
+    let norm_u = u.norm();
+    let norm_v = v.norm();
+
+    if norm_u == 0.0 || norm_v == 0.0 {
+        panic!("One or both vectors are zero");
+    }
+
+    (u.dot(v).real() / (norm_u * norm_v)) as f32
+}
+
+pub fn cross_product<K: Ring>(u: &Vector<K, 3>, v: &Vector<K, 3>) -> Vector<K, 3> {
+    Vector::new([
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ])
+}
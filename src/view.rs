@@ -0,0 +1,698 @@
+use crate::dmatrix::DMatrix;
+use crate::dvector::DVector;
+use crate::field::{RealField, Ring};
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
+
+/// Read access shared by owned vectors and vector views, so `dot` and
+/// `mul_vec` accept either without copying.
+pub trait VectorAccess<K> {
+    fn dim(&self) -> usize;
+
+    fn at(&self, index: usize) -> K;
+}
+
+impl<K: Ring, const N: usize> VectorAccess<K> for Vector<K, N> {
+    fn dim(&self) -> usize {
+        N
+    }
+
+    fn at(&self, index: usize) -> K {
+        self[index]
+    }
+}
+
+impl<K: Ring> VectorAccess<K> for DVector<K> {
+    fn dim(&self) -> usize {
+        self.len()
+    }
+
+    fn at(&self, index: usize) -> K {
+        self[index]
+    }
+}
+
+impl<K: Ring> VectorAccess<K> for [K] {
+    fn dim(&self) -> usize {
+        self.len()
+    }
+
+    fn at(&self, index: usize) -> K {
+        self[index]
+    }
+}
+
+/// Last flat index touched by `len` elements spaced `stride` apart.
+fn check_extent(data_len: usize, start: usize, len: usize, stride: usize) {
+    if len > 0 {
+        assert!(
+            start + (len - 1) * stride < data_len,
+            "View exceeds the underlying storage"
+        );
+    }
+}
+
+/// Non-owning, possibly strided, view of `len` elements.
+#[derive(Debug, Clone, Copy)]
+pub struct VectorView<'a, K> {
+    data: &'a [K],
+    start: usize,
+    len: usize,
+    stride: usize,
+}
+
+#[derive(Debug)]
+pub struct VectorViewMut<'a, K> {
+    data: &'a mut [K],
+    start: usize,
+    len: usize,
+    stride: usize,
+}
+
+/// Non-owning view of a `rows × cols` block, addressed by `(row, col)`.
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'a, K> {
+    data: &'a [K],
+    start: usize,
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+}
+
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, K> {
+    data: &'a mut [K],
+    start: usize,
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+}
+
+impl<'a, K: Ring> VectorView<'a, K> {
+    pub fn new(data: &'a [K], start: usize, len: usize, stride: usize) -> Self {
+        check_extent(data.len(), start, len, stride);
+        VectorView {
+            data,
+            start,
+            len,
+            stride,
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = K> + '_ {
+        (0..self.len).map(|i| self.data[self.start + i * self.stride])
+    }
+
+    pub fn to_dvector(&self) -> DVector<K> {
+        DVector::new(self.iter().collect())
+    }
+}
+
+impl<'a, K: Ring> VectorViewMut<'a, K> {
+    pub fn new(data: &'a mut [K], start: usize, len: usize, stride: usize) -> Self {
+        check_extent(data.len(), start, len, stride);
+        VectorViewMut {
+            data,
+            start,
+            len,
+            stride,
+        }
+    }
+
+    pub fn as_view(&self) -> VectorView<'_, K> {
+        VectorView::new(self.data, self.start, self.len, self.stride)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = K> + '_ {
+        (0..self.len).map(|i| self.data[self.start + i * self.stride])
+    }
+
+    fn operate<V: VectorAccess<K> + ?Sized, F: Fn(K, K) -> K>(&mut self, v: &V, op: F) {
+        assert_eq!(self.len, v.dim(), "Vector dimensions must match");
+        for i in 0..self.len {
+            self[i] = op(self[i], v.at(i));
+        }
+    }
+
+    pub fn add<V: VectorAccess<K> + ?Sized>(&mut self, v: &V) {
+        self.operate(v, |a, b| a + b);
+    }
+
+    pub fn sub<V: VectorAccess<K> + ?Sized>(&mut self, v: &V) {
+        self.operate(v, |a, b| a - b);
+    }
+
+    pub fn scl(&mut self, scalar: K) {
+        for i in 0..self.len {
+            self[i] *= scalar;
+        }
+    }
+
+    pub fn fill(&mut self, value: K) {
+        for i in 0..self.len {
+            self[i] = value;
+        }
+    }
+}
+
+impl<'a, K: Ring> MatrixView<'a, K> {
+    pub fn new(data: &'a [K], start: usize, rows: usize, cols: usize, row_stride: usize) -> Self {
+        if cols > 0 {
+            check_extent(data.len(), start + cols - 1, rows, row_stride);
+        }
+        MatrixView {
+            data,
+            start,
+            rows,
+            cols,
+            row_stride,
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row(&self, row: usize) -> VectorView<'a, K> {
+        assert!(row < self.rows, "Index out of bounds");
+        VectorView::new(self.data, self.start + row * self.row_stride, self.cols, 1)
+    }
+
+    pub fn column(&self, col: usize) -> VectorView<'a, K> {
+        assert!(col < self.cols, "Index out of bounds");
+        VectorView::new(self.data, self.start + col, self.rows, self.row_stride)
+    }
+
+    pub fn diagonal(&self) -> VectorView<'a, K> {
+        VectorView::new(
+            self.data,
+            self.start,
+            self.rows.min(self.cols),
+            self.row_stride + 1,
+        )
+    }
+
+    pub fn submatrix(&self, row: usize, col: usize, rows: usize, cols: usize) -> MatrixView<'a, K> {
+        assert!(
+            row + rows <= self.rows && col + cols <= self.cols,
+            "Index out of bounds"
+        );
+        MatrixView::new(
+            self.data,
+            self.start + row * self.row_stride + col,
+            rows,
+            cols,
+            self.row_stride,
+        )
+    }
+
+    pub fn mul_vec<V: VectorAccess<K> + ?Sized>(&self, rhs: &V) -> DVector<K> {
+        assert_eq!(self.cols, rhs.dim(), "Vector dimension must match columns");
+        // Not `row(i).dot(rhs)`, which conjugates the row.
+        DVector::new(
+            (0..self.rows)
+                .map(|i| {
+                    self.row(i)
+                        .iter()
+                        .enumerate()
+                        .fold(K::zero(), |acc, (j, a)| acc + a * rhs.at(j))
+                })
+                .collect(),
+        )
+    }
+
+    pub fn transpose(&self) -> DMatrix<K> {
+        let mut result = DMatrix::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                result[j][i] = self[(i, j)];
+            }
+        }
+        result
+    }
+
+    pub fn trace(&self) -> K {
+        assert_eq!(self.rows, self.cols, "Matrix must be square");
+        self.diagonal().iter().fold(K::zero(), |acc, x| acc + x)
+    }
+
+    pub fn to_dmatrix(&self) -> DMatrix<K> {
+        DMatrix::new(
+            self.rows,
+            self.cols,
+            (0..self.rows)
+                .flat_map(|i| self.row(i).iter().collect::<Vec<_>>())
+                .collect(),
+        )
+    }
+}
+
+impl<'a, K: Ring> MatrixViewMut<'a, K> {
+    pub fn new(
+        data: &'a mut [K],
+        start: usize,
+        rows: usize,
+        cols: usize,
+        row_stride: usize,
+    ) -> Self {
+        if cols > 0 {
+            check_extent(data.len(), start + cols - 1, rows, row_stride);
+        }
+        MatrixViewMut {
+            data,
+            start,
+            rows,
+            cols,
+            row_stride,
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn as_view(&self) -> MatrixView<'_, K> {
+        MatrixView::new(self.data, self.start, self.rows, self.cols, self.row_stride)
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> VectorViewMut<'_, K> {
+        assert!(row < self.rows, "Index out of bounds");
+        VectorViewMut::new(self.data, self.start + row * self.row_stride, self.cols, 1)
+    }
+
+    pub fn column_mut(&mut self, col: usize) -> VectorViewMut<'_, K> {
+        assert!(col < self.cols, "Index out of bounds");
+        VectorViewMut::new(self.data, self.start + col, self.rows, self.row_stride)
+    }
+
+    pub fn diagonal_mut(&mut self) -> VectorViewMut<'_, K> {
+        let len = self.rows.min(self.cols);
+        VectorViewMut::new(self.data, self.start, len, self.row_stride + 1)
+    }
+
+    fn operate<F: Fn(K, K) -> K>(&mut self, v: &MatrixView<'_, K>, op: F) {
+        assert_eq!(
+            (self.rows, self.cols),
+            (v.rows, v.cols),
+            "Matrix dimensions must match"
+        );
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                self[(i, j)] = op(self[(i, j)], v[(i, j)]);
+            }
+        }
+    }
+
+    pub fn add(&mut self, v: &MatrixView<'_, K>) {
+        self.operate(v, |a, b| a + b);
+    }
+
+    pub fn sub(&mut self, v: &MatrixView<'_, K>) {
+        self.operate(v, |a, b| a - b);
+    }
+
+    pub fn scl(&mut self, scalar: K) {
+        for i in 0..self.rows {
+            self.row_mut(i).scl(scalar);
+        }
+    }
+
+    pub fn copy_from(&mut self, v: &MatrixView<'_, K>) {
+        self.operate(v, |_, b| b);
+    }
+}
+
+impl<K: Ring> VectorAccess<K> for VectorView<'_, K> {
+    fn dim(&self) -> usize {
+        self.len
+    }
+
+    fn at(&self, index: usize) -> K {
+        self[index]
+    }
+}
+
+impl<K: Ring> VectorAccess<K> for VectorViewMut<'_, K> {
+    fn dim(&self) -> usize {
+        self.len
+    }
+
+    fn at(&self, index: usize) -> K {
+        self[index]
+    }
+}
+
+impl<K: Ring> VectorView<'_, K> {
+    pub fn dot<V: VectorAccess<K> + ?Sized>(&self, v: &V) -> K {
+        assert_eq!(self.len, v.dim(), "Vector dimensions must match");
+        self.iter()
+            .enumerate()
+            .fold(K::zero(), |acc, (i, x)| acc + x.conjugate() * v.at(i))
+    }
+}
+
+impl<K: RealField> VectorView<'_, K> {
+    pub fn norm_1(&self) -> f64 {
+        self.iter().fold(f64::default(), |acc, x| acc + x.modulus())
+    }
+
+    pub fn norm(&self) -> f64 {
+        self.dot(self).modulus().sqrt()
+    }
+
+    pub fn norm_inf(&self) -> f64 {
+        self.iter()
+            .fold(f64::NEG_INFINITY, |max, x| x.modulus().max(max))
+    }
+}
+
+impl<K> Index<usize> for VectorView<'_, K> {
+    type Output = K;
+
+    fn index(&self, index: usize) -> &K {
+        assert!(index < self.len, "Index out of bounds");
+        &self.data[self.start + index * self.stride]
+    }
+}
+
+impl<K> Index<usize> for VectorViewMut<'_, K> {
+    type Output = K;
+
+    fn index(&self, index: usize) -> &K {
+        assert!(index < self.len, "Index out of bounds");
+        &self.data[self.start + index * self.stride]
+    }
+}
+
+impl<K> IndexMut<usize> for VectorViewMut<'_, K> {
+    fn index_mut(&mut self, index: usize) -> &mut K {
+        assert!(index < self.len, "Index out of bounds");
+        &mut self.data[self.start + index * self.stride]
+    }
+}
+
+impl<K> Index<(usize, usize)> for MatrixView<'_, K> {
+    type Output = K;
+
+    fn index(&self, (row, col): (usize, usize)) -> &K {
+        assert!(row < self.rows && col < self.cols, "Index out of bounds");
+        &self.data[self.start + row * self.row_stride + col]
+    }
+}
+
+impl<K> Index<(usize, usize)> for MatrixViewMut<'_, K> {
+    type Output = K;
+
+    fn index(&self, (row, col): (usize, usize)) -> &K {
+        assert!(row < self.rows && col < self.cols, "Index out of bounds");
+        &self.data[self.start + row * self.row_stride + col]
+    }
+}
+
+impl<K> IndexMut<(usize, usize)> for MatrixViewMut<'_, K> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut K {
+        assert!(row < self.rows && col < self.cols, "Index out of bounds");
+        &mut self.data[self.start + row * self.row_stride + col]
+    }
+}
+
+impl<K: Ring, V: VectorAccess<K>> Add<&V> for VectorView<'_, K> {
+    type Output = DVector<K>;
+
+    fn add(self, rhs: &V) -> Self::Output {
+        assert_eq!(self.len, rhs.dim(), "Vector dimensions must match");
+        DVector::new(
+            self.iter()
+                .enumerate()
+                .map(|(i, x)| x + rhs.at(i))
+                .collect(),
+        )
+    }
+}
+
+impl<K: Ring, V: VectorAccess<K>> Sub<&V> for VectorView<'_, K> {
+    type Output = DVector<K>;
+
+    fn sub(self, rhs: &V) -> Self::Output {
+        assert_eq!(self.len, rhs.dim(), "Vector dimensions must match");
+        DVector::new(
+            self.iter()
+                .enumerate()
+                .map(|(i, x)| x - rhs.at(i))
+                .collect(),
+        )
+    }
+}
+
+impl<K: Ring> Mul<K> for VectorView<'_, K> {
+    type Output = DVector<K>;
+
+    fn mul(self, scalar: K) -> Self::Output {
+        self.to_dvector() * scalar
+    }
+}
+
+impl<K: Ring, V: VectorAccess<K>> AddAssign<&V> for VectorViewMut<'_, K> {
+    fn add_assign(&mut self, rhs: &V) {
+        self.add(rhs);
+    }
+}
+
+impl<K: Ring, V: VectorAccess<K>> SubAssign<&V> for VectorViewMut<'_, K> {
+    fn sub_assign(&mut self, rhs: &V) {
+        self.sub(rhs);
+    }
+}
+
+impl<K: Ring> MulAssign<K> for VectorViewMut<'_, K> {
+    fn mul_assign(&mut self, scalar: K) {
+        self.scl(scalar);
+    }
+}
+
+impl<K: Ring> Add<MatrixView<'_, K>> for MatrixView<'_, K> {
+    type Output = DMatrix<K>;
+
+    fn add(self, rhs: MatrixView<'_, K>) -> Self::Output {
+        self.to_dmatrix() + rhs.to_dmatrix()
+    }
+}
+
+impl<K: Ring> Sub<MatrixView<'_, K>> for MatrixView<'_, K> {
+    type Output = DMatrix<K>;
+
+    fn sub(self, rhs: MatrixView<'_, K>) -> Self::Output {
+        self.to_dmatrix() - rhs.to_dmatrix()
+    }
+}
+
+impl<K: Ring> Mul<K> for MatrixView<'_, K> {
+    type Output = DMatrix<K>;
+
+    fn mul(self, scalar: K) -> Self::Output {
+        self.to_dmatrix() * scalar
+    }
+}
+
+impl<K: Ring> AddAssign<MatrixView<'_, K>> for MatrixViewMut<'_, K> {
+    fn add_assign(&mut self, rhs: MatrixView<'_, K>) {
+        self.add(&rhs);
+    }
+}
+
+impl<K: Ring> SubAssign<MatrixView<'_, K>> for MatrixViewMut<'_, K> {
+    fn sub_assign(&mut self, rhs: MatrixView<'_, K>) {
+        self.sub(&rhs);
+    }
+}
+
+impl<K: Ring> MulAssign<K> for MatrixViewMut<'_, K> {
+    fn mul_assign(&mut self, scalar: K) {
+        self.scl(scalar);
+    }
+}
+
+impl<K: Ring, const N: usize> Vector<K, N> {
+    /// Every `stride`-th element starting at `start`.
+    pub fn strided(&self, start: usize, len: usize, stride: usize) -> VectorView<'_, K> {
+        VectorView::new(self, start, len, stride)
+    }
+
+    pub fn strided_mut(&mut self, start: usize, len: usize, stride: usize) -> VectorViewMut<'_, K> {
+        VectorViewMut::new(self, start, len, stride)
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> Matrix<K, M, N> {
+    pub fn as_view(&self) -> MatrixView<'_, K> {
+        MatrixView::new(self.as_flattened(), 0, M, N, N)
+    }
+
+    pub fn as_view_mut(&mut self) -> MatrixViewMut<'_, K> {
+        MatrixViewMut::new(self.as_flattened_mut(), 0, M, N, N)
+    }
+
+    pub fn submatrix(&self, row: usize, col: usize, rows: usize, cols: usize) -> MatrixView<'_, K> {
+        self.as_view().submatrix(row, col, rows, cols)
+    }
+
+    pub fn submatrix_mut(
+        &mut self,
+        row: usize,
+        col: usize,
+        rows: usize,
+        cols: usize,
+    ) -> MatrixViewMut<'_, K> {
+        assert!(row + rows <= M && col + cols <= N, "Index out of bounds");
+        MatrixViewMut::new(self.as_flattened_mut(), row * N + col, rows, cols, N)
+    }
+
+    pub fn row(&self, row: usize) -> VectorView<'_, K> {
+        self.as_view().row(row)
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> VectorViewMut<'_, K> {
+        assert!(row < M, "Index out of bounds");
+        VectorViewMut::new(self.as_flattened_mut(), row * N, N, 1)
+    }
+
+    pub fn column(&self, col: usize) -> VectorView<'_, K> {
+        self.as_view().column(col)
+    }
+
+    pub fn column_mut(&mut self, col: usize) -> VectorViewMut<'_, K> {
+        assert!(col < N, "Index out of bounds");
+        VectorViewMut::new(self.as_flattened_mut(), col, M, N)
+    }
+
+    pub fn diagonal(&self) -> VectorView<'_, K> {
+        self.as_view().diagonal()
+    }
+
+    pub fn diagonal_mut(&mut self) -> VectorViewMut<'_, K> {
+        VectorViewMut::new(self.as_flattened_mut(), 0, M.min(N), N + 1)
+    }
+}
+
+impl<K: Ring, const M: usize, const N: usize> TryFrom<MatrixView<'_, K>> for Matrix<K, M, N> {
+    type Error = &'static str;
+
+    fn try_from(view: MatrixView<'_, K>) -> Result<Self, Self::Error> {
+        Matrix::try_from(view.to_dmatrix())
+    }
+}
+
+impl<K: Ring, const N: usize> TryFrom<VectorView<'_, K>> for Vector<K, N> {
+    type Error = &'static str;
+
+    fn try_from(view: VectorView<'_, K>) -> Result<Self, Self::Error> {
+        Vector::try_from(view.to_dvector())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use num_complex::Complex;
+
+    const THRESHOLD: f64 = 1e-8;
+
+    fn sample() -> Matrix<i64, 3, 4> {
+        Matrix::new([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]])
+    }
+
+    #[test]
+    fn view_rows_columns_diagonal() {
+        let u = sample();
+
+        assert_eq!(u.row(1).to_dvector(), DVector::new(vec![5, 6, 7, 8]));
+        assert_eq!(u.column(2).to_dvector(), DVector::new(vec![3, 7, 11]));
+        assert_eq!(u.diagonal().to_dvector(), DVector::new(vec![1, 6, 11]));
+
+        let block = u.submatrix(1, 1, 2, 3);
+        assert_eq!((block.rows(), block.cols()), (2, 3));
+        assert_eq!(block[(1, 2)], 12);
+        assert_eq!(block.column(0).to_dvector(), DVector::new(vec![6, 10]));
+        assert_eq!(block.diagonal().to_dvector(), DVector::new(vec![6, 11]));
+        assert_eq!(block.submatrix(0, 0, 2, 2).trace(), 17);
+        assert_eq!(
+            Matrix::<i64, 2, 3>::try_from(block),
+            Ok(Matrix::new([[6, 7, 8], [10, 11, 12]]))
+        );
+        assert_eq!(
+            block.transpose(),
+            DMatrix::from_rows(&[vec![6, 10], vec![7, 11], vec![8, 12]])
+        );
+
+        let v = Vector::new([1, 2, 3, 4, 5, 6]);
+        assert_eq!(v.strided(1, 3, 2).to_dvector(), DVector::new(vec![2, 4, 6]));
+    }
+
+    #[test]
+    fn view_products() {
+        let u = sample();
+        let x = Vector::new([1, 0, -1]);
+
+        // Column 1 is [2, 6, 10], so no copy is needed to dot it with `x`.
+        assert_eq!(x.dot(&u.column(1)), -8);
+        assert_eq!(u.column(1).dot(&u.column(3)), 2 * 4 + 6 * 8 + 10 * 12);
+
+        let square = Matrix::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(square.mul_vec(&u.column(0)), Vector::new([38, 83, 128]));
+        assert_eq!(
+            u.submatrix(0, 0, 2, 2).mul_vec(&u.row(2).to_dvector()[..2]),
+            DVector::new(vec![29, 105])
+        );
+
+        let c = Matrix::new([
+            [Complex::new(1., 2.), Complex::new(0., -1.)],
+            [Complex::new(3., 0.), Complex::new(-2., 1.)],
+        ]);
+        let z = Vector::new([Complex::new(1., 1.), Complex::new(2., -1.)]);
+        assert_eq!(
+            c.as_view().mul_vec(&z),
+            DVector::new(c.mul_vec(&z).to_vec())
+        );
+
+        let f = Matrix::new([[3.0, 0.0], [4.0, 1.0]]);
+        assert_abs_diff_eq!(f.column(0).norm(), 5.0, epsilon = THRESHOLD);
+        assert_abs_diff_eq!(f.row(1).norm_1(), 5.0, epsilon = THRESHOLD);
+        assert_abs_diff_eq!(f.diagonal().norm_inf(), 3.0, epsilon = THRESHOLD);
+    }
+
+    #[test]
+    fn view_mutation() {
+        let mut u = sample();
+        u.row_mut(0).scl(2);
+        u.column_mut(3).add(&Vector::new([1, 1, 1]));
+        u.diagonal_mut().fill(0);
+        assert_eq!(u, Matrix::new([[0, 4, 6, 9], [5, 0, 7, 9], [9, 10, 0, 13]]));
+
+        let mut block = u.submatrix_mut(1, 0, 2, 2);
+        block *= -1;
+        block[(0, 1)] = 42;
+        assert_eq!(u[1], [-5, 42, 7, 9]);
+        assert_eq!(u[2], [-9, -10, 0, 13]);
+
+        let source = sample();
+        let mut target = Matrix::new([[0; 4]; 3]);
+        let mut block = target.submatrix_mut(0, 2, 3, 2);
+        block += source.submatrix(0, 0, 3, 2);
+        assert_eq!(target.column(3).to_dvector(), DVector::new(vec![2, 6, 10]));
+        assert_eq!(
+            source.row(0) + &Vector::new([1, 1, 1, 1]),
+            DVector::new(vec![2, 3, 4, 5])
+        );
+    }
+}